@@ -46,6 +46,31 @@ fn error(span: Span, message: &str) -> TokenStream {
     ])
 }
 
+/// Number of base `base` digits of the largest `bits` bit number.
+///
+/// Copy of `max_digits` in the `string` module of `ruint`. It can not be
+/// shared because `ruint` depends on this crate, so keep the two in sync.
+#[allow(clippy::cast_precision_loss)]
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+fn max_digits(bits: usize, base: u8) -> usize {
+    if bits == 0 {
+        return 0;
+    }
+    if base.is_power_of_two() {
+        let bits_per_digit = base.trailing_zeros() as usize;
+        return bits.div_ceil(bits_per_digit);
+    }
+    // Irrational for `bits > 0`, so rounding up is exact except extremely
+    // close to an integer. Then err on the long side.
+    let digits = bits as f64 / f64::from(base).log2();
+    if (digits - digits.round()).abs() < 1e-9 {
+        digits.round() as usize + 1
+    } else {
+        digits.ceil() as usize
+    }
+}
+
 /// Parse a value literal and bits suffix into a Uint literal.
 fn parse(value: &str, bits: &str) -> Result<TokenStream, String> {
     // Parse bit length
@@ -77,6 +102,19 @@ fn parse(value: &str, bits: &str) -> Result<TokenStream, String> {
         (10, value)
     };
 
+    // Reject literals with more digits than the largest value early
+    let significant = digits
+        .chars()
+        .filter(|&c| c != '_')
+        .skip_while(|&c| c == '0')
+        .count();
+    let max = max_digits(bits, base);
+    if significant > max {
+        return Err(format!(
+            "Value too large for Uint<{bits}>: more than {max} digits"
+        ));
+    }
+
     // Parse digits in base
     let mut limbs = vec![0_u64];
     for c in digits.chars() {
@@ -189,6 +227,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_max_digits() {
+        assert_eq!(super::max_digits(0, 10), 0);
+        assert_eq!(super::max_digits(8, 10), 3);
+        assert_eq!(super::max_digits(64, 10), 20);
+        assert_eq!(super::max_digits(256, 16), 64);
+        assert_eq!(super::max_digits(9, 8), 3);
+        assert_eq!(super::max_digits(10, 8), 4);
+        assert_eq!(
+            super::parse("1000", "8").unwrap_err(),
+            "Value too large for Uint<8>: more than 3 digits"
+        );
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        uint! {
+            assert_eq!(0000000000000000000000000255_U8, Uint::from(255));
+            assert_eq!(0x0000_0000_0000_0000_0000_ff_U8, Uint::from(255));
+        }
+    }
+
     #[test]
     fn test_bases() {
         uint! {
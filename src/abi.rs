@@ -0,0 +1,314 @@
+use crate::Uint;
+use thiserror::Error;
+
+/// Error for the Ethereum ABI conversions like [`Uint::from_abi_word`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum AbiError {
+    /// Bits above `BITS` are set in an unsigned value.
+    ///
+    /// `.0` is `BITS`.
+    #[error("ABI value has non-zero bits above bit {0}")]
+    DirtyHighBits(usize),
+
+    /// Bits above `BITS` are not a sign-extension of a signed value.
+    ///
+    /// `.0` is `BITS`.
+    #[error("ABI value is not a sign-extended int{0}")]
+    InvalidSignExtension(usize),
+
+    /// A packed value does not have the natural byte length.
+    ///
+    /// `.0` is the provided length and `.1` is the expected length.
+    #[error("packed ABI value has length {0}, expected {1}")]
+    InvalidLength(usize, usize),
+}
+
+/// Size of an ABI word in bytes.
+const WORD: usize = 32;
+
+/// Number of `u64` limbs in an ABI word.
+const WORD_LIMBS: usize = WORD / 8;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Encodes the [`Uint`] as a 32-byte ABI word for `uint<BITS>`.
+    ///
+    /// The value is big-endian and left-padded with zeros.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BITS` is larger than `256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// let word = 0x1234_U16.to_abi_word();
+    /// assert_eq!(word[..30], [0; 30]);
+    /// assert_eq!(word[30..], [0x12, 0x34]);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_abi_word(&self) -> [u8; 32] {
+        Self::assert_abi_size();
+        let mut limbs = [0; WORD_LIMBS];
+        limbs[..LIMBS].copy_from_slice(self.as_limbs());
+        word_from_limbs(limbs)
+    }
+
+    /// Decodes a 32-byte ABI word for `uint<BITS>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AbiError::DirtyHighBits`] if any of the bits above `BITS` are
+    /// set. The ABI specification requires these to be zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BITS` is larger than `256`.
+    pub fn from_abi_word(word: &[u8; 32]) -> Result<Self, AbiError> {
+        Self::assert_abi_size();
+        Self::checked_from_limbs_slice(&limbs_from_word(word)).ok_or(AbiError::DirtyHighBits(BITS))
+    }
+
+    /// Encodes the [`Uint`] as a 32-byte ABI word for `int<BITS>`.
+    ///
+    /// The [`Uint`] is interpreted as a two's complement signed integer, i.e.
+    /// the most significant bit is the sign bit. The value is sign-extended to
+    /// 256 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BITS` is larger than `256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// // The 16-bit two's complement representation of -2.
+    /// let word = 0xfffe_U16.to_abi_word_signed();
+    /// assert_eq!(word[..31], [0xff; 31]);
+    /// assert_eq!(word[31], 0xfe);
+    /// assert_eq!(0x7ffe_U16.to_abi_word_signed(), 0x7ffe_U16.to_abi_word());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_abi_word_signed(&self) -> [u8; 32] {
+        Self::assert_abi_size();
+        let mut limbs = [0; WORD_LIMBS];
+        limbs[..LIMBS].copy_from_slice(self.as_limbs());
+        if self.is_abi_negative() {
+            for (i, limb) in limbs.iter_mut().enumerate() {
+                *limb |= high_mask(BITS, i);
+            }
+        }
+        word_from_limbs(limbs)
+    }
+
+    /// Decodes a 32-byte ABI word for `int<BITS>`.
+    ///
+    /// The result is the two's complement representation of the signed value
+    /// in `BITS` bits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AbiError::InvalidSignExtension`] if the bits above `BITS` are
+    /// not all equal to the sign bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BITS` is larger than `256`.
+    pub fn from_abi_word_signed(word: &[u8; 32]) -> Result<Self, AbiError> {
+        Self::assert_abi_size();
+        let mut limbs = limbs_from_word(word);
+        let negative = BITS > 0 && limbs[(BITS - 1) / 64] & (1 << ((BITS - 1) % 64)) != 0;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let mask = high_mask(BITS, i);
+            let expected = if negative { mask } else { 0 };
+            if *limb & mask != expected {
+                return Err(AbiError::InvalidSignExtension(BITS));
+            }
+            *limb &= !mask;
+        }
+        Ok(Self::from_limbs_slice(&limbs[..LIMBS]))
+    }
+
+    /// Encodes the [`Uint`] in the packed (`abi.encodePacked`) format.
+    ///
+    /// This is the big-endian value at its natural width of
+    /// [`Self::BYTES`] bytes. For `int<BITS>` with `BITS` a multiple of eight
+    /// this is also the correct two's complement encoding.
+    #[must_use]
+    pub fn to_abi_packed(&self) -> Vec<u8> {
+        self.to_be_bytes_vec()
+    }
+
+    /// Decodes a value in the packed (`abi.encodePacked`) format.
+    ///
+    /// # Errors
+    ///
+    /// * [`AbiError::InvalidLength`] if the input is not exactly
+    ///   [`Self::BYTES`] long.
+    /// * [`AbiError::DirtyHighBits`] if any of the bits above `BITS` are set.
+    pub fn from_abi_packed(bytes: &[u8]) -> Result<Self, AbiError> {
+        if bytes.len() != Self::BYTES {
+            return Err(AbiError::InvalidLength(bytes.len(), Self::BYTES));
+        }
+        Self::try_from_be_slice(bytes).ok_or(AbiError::DirtyHighBits(BITS))
+    }
+
+    /// Returns `true` if the sign bit of `int<BITS>` is set.
+    const fn is_abi_negative(&self) -> bool {
+        BITS > 0 && self.bit(BITS - 1)
+    }
+
+    #[track_caller]
+    const fn assert_abi_size() {
+        assert!(BITS <= 256, "ABI words are limited to 256 bits");
+    }
+}
+
+/// Mask of the bits in limb `index` that are at or above bit `bits`.
+const fn high_mask(bits: usize, index: usize) -> u64 {
+    let start = index * 64;
+    if bits <= start {
+        u64::MAX
+    } else if bits >= start + 64 {
+        0
+    } else {
+        u64::MAX << (bits - start)
+    }
+}
+
+fn word_from_limbs(limbs: [u64; WORD_LIMBS]) -> [u8; 32] {
+    let mut word = [0; WORD];
+    for (chunk, limb) in word.rchunks_exact_mut(8).zip(limbs) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    word
+}
+
+fn limbs_from_word(word: &[u8; 32]) -> [u64; WORD_LIMBS] {
+    let mut limbs = [0; WORD_LIMBS];
+    for (limb, chunk) in limbs.iter_mut().zip(word.rchunks_exact(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases::{U128, U256, U64, U8},
+        const_for, nlimbs,
+    };
+    use hex_literal::hex;
+    use proptest::proptest;
+
+    #[test]
+    fn test_abi_word() {
+        assert_eq!(
+            U256::from(0x1234_5678).to_abi_word(),
+            hex!("0000000000000000000000000000000000000000000000000000000012345678")
+        );
+        assert_eq!(
+            U8::from_abi_word(&hex!(
+                "00000000000000000000000000000000000000000000000000000000000000ff"
+            )),
+            Ok(U8::from(0xff))
+        );
+        assert_eq!(
+            U8::from_abi_word(&hex!(
+                "0000000000000000000000000000000000000000000000000000000000000100"
+            )),
+            Err(AbiError::DirtyHighBits(8))
+        );
+        assert_eq!(
+            Uint::<4, 1>::from_abi_word(&hex!(
+                "0000000000000000000000000000000000000000000000000000000000000010"
+            )),
+            Err(AbiError::DirtyHighBits(4))
+        );
+    }
+
+    #[test]
+    fn test_abi_word_signed() {
+        assert_eq!(
+            U8::from(0xfe).to_abi_word_signed(),
+            hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe")
+        );
+        assert_eq!(
+            U8::from_abi_word_signed(&hex!(
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe"
+            )),
+            Ok(U8::from(0xfe))
+        );
+        assert_eq!(
+            U8::from_abi_word_signed(&hex!(
+                "00000000000000000000000000000000000000000000000000000000000000fe"
+            )),
+            Err(AbiError::InvalidSignExtension(8))
+        );
+        assert_eq!(
+            U8::from_abi_word_signed(&hex!(
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7e"
+            )),
+            Err(AbiError::InvalidSignExtension(8))
+        );
+    }
+
+    #[test]
+    fn test_abi_packed() {
+        assert_eq!(
+            Uint::<24, 1>::from(0x12_3456).to_abi_packed(),
+            hex!("123456")
+        );
+        assert_eq!(
+            Uint::<24, 1>::from_abi_packed(&hex!("123456")),
+            Ok(Uint::from(0x12_3456))
+        );
+        assert_eq!(
+            Uint::<24, 1>::from_abi_packed(&hex!("00123456")),
+            Err(AbiError::InvalidLength(4, 3))
+        );
+        assert_eq!(
+            Uint::<12, 1>::from_abi_packed(&hex!("1234")),
+            Err(AbiError::DirtyHighBits(12))
+        );
+    }
+
+    #[test]
+    fn test_abi_native() {
+        proptest!(|(value: i64)| {
+            let mut expected = [if value < 0 { 0xff } else { 0 }; 32];
+            expected[24..].copy_from_slice(&value.to_be_bytes());
+            #[allow(clippy::cast_sign_loss)]
+            let n = U64::from(value as u64);
+            assert_eq!(n.to_abi_word_signed(), expected);
+            assert_eq!(U64::from_abi_word_signed(&expected), Ok(n));
+        });
+        proptest!(|(value: i128)| {
+            let mut expected = [if value < 0 { 0xff } else { 0 }; 32];
+            expected[16..].copy_from_slice(&value.to_be_bytes());
+            #[allow(clippy::cast_sign_loss)]
+            let n = U128::from(value as u128);
+            assert_eq!(n.to_abi_word_signed(), expected);
+            assert_eq!(U128::from_abi_word_signed(&expected), Ok(n));
+        });
+    }
+
+    #[test]
+    fn test_abi_roundtrip() {
+        const_for!(BITS in SIZES if (BITS < 257) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                assert_eq!(U::from_abi_word(&value.to_abi_word()), Ok(value));
+                assert_eq!(U::from_abi_word_signed(&value.to_abi_word_signed()), Ok(value));
+                assert_eq!(U::from_abi_packed(&value.to_abi_packed()), Ok(value));
+            });
+        });
+    }
+}
@@ -212,10 +212,17 @@ pub fn divrem_nbym(numerator: &mut [u64], divisor: &mut [u64]) {
     // D2. Loop over quotient digits
     for j in (0..=m).rev() {
         // D3. Calculate approximate quotient word
-        let mut qhat = div_3by2(
-            &[numerator[j + n - 2], numerator[j + n - 1], numerator[j + n]],
-            &[divisor[n - 2], divisor[n - 1]],
-        );
+        // The top limbs are at most those of the divisor. When they are equal
+        // the quotient word does not fit `div_3by2`, but it is `u64::MAX`.
+        let mut qhat =
+            if numerator[j + n] == divisor[n - 1] && numerator[j + n - 1] == divisor[n - 2] {
+                u64::MAX
+            } else {
+                div_3by2(
+                    &[numerator[j + n - 2], numerator[j + n - 1], numerator[j + n]],
+                    &[divisor[n - 2], divisor[n - 1]],
+                )
+            };
 
         // D4. Multiply and subtract.
         let mut borrow = 0;
@@ -354,6 +361,17 @@ mod tests {
         assert_eq!(quotient, 1);
     }
 
+    #[test]
+    fn test_div_rem_equal_top() {
+        // After normalizing, the top two limbs of the remaining numerator equal
+        // those of the divisor, so the quotient word is `FULL`.
+        let mut numerator = [0, 0, 0, 0, 0, 0, 0, 0, 1];
+        let mut divisor = [FULL, FULL, 0, FULL];
+        div_rem(&mut numerator, &mut divisor);
+        assert_eq!(numerator, [0, FULL, FULL, 0, 1, 0, 0, 0, 0]);
+        assert_eq!(divisor, [0, FULL, FULL, 1]);
+    }
+
     // proptest!(
     // #[test]
     // fn div_3by2_correct(q: u64, d0: u64, d1: u64) {
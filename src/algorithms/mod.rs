@@ -9,7 +9,7 @@ mod mul;
 mod mul_redc;
 
 pub use self::{
-    div::div_rem,
+    div::{div_rem, divrem_nby1},
     gcd::{gcd, gcd_extended, inv_mod, LehmerMatrix},
     mul::{mul, mul_inline},
     mul_redc::mul_redc,
@@ -0,0 +1,391 @@
+use crate::{
+    base_convert::{limbs_from_digits_be, limbs_to_digits_be},
+    string::max_digits,
+    ParseError, ParseErrorKind, Uint,
+};
+
+/// Marks bytes that are not a digit in [`Alphabet::values`].
+const INVALID: u8 = u8::MAX;
+
+/// A set of digits for formatting and parsing [`Uint`]s.
+///
+/// The radix is the number of digits, and the first digit represents zero.
+/// Digits are ASCII characters. See [`Uint::to_string_alphabet`] and
+/// [`Uint::from_str_alphabet`].
+///
+/// Note that these encode the value of the integer. Byte oriented encodings,
+/// like the padded base32 of RFC 4648, differ in leading zeros and padding.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::{Alphabet, Uint, uint};
+/// # uint!{
+/// const DNA: Alphabet = Alphabet::new("ACGT").case_insensitive();
+/// assert_eq!(27_U64.to_string_alphabet(&DNA), "CGT");
+/// assert_eq!(Uint::from_str_alphabet("cgt", &DNA), Ok(27_U64));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Alphabet {
+    digits: &'static [u8],
+    values: [u8; 128],
+}
+
+impl Alphabet {
+    /// Lower case `0`—`9`, `a`—`z`. Parsing is case insensitive.
+    pub const BASE36: Self = Self::new("0123456789abcdefghijklmnopqrstuvwxyz").case_insensitive();
+
+    /// The Bitcoin base58 alphabet.
+    ///
+    /// This omits `0`, `O`, `I` and `l` from the alphanumeric characters.
+    pub const BASE58: Self =
+        Self::new("123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+
+    /// The RFC 4648 base32 alphabet `A`—`Z`, `2`—`7`. Parsing is case
+    /// insensitive.
+    pub const BASE32: Self = Self::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567").case_insensitive();
+
+    /// Creates a case sensitive alphabet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than two digits, a digit is not ASCII, or a
+    /// digit is repeated.
+    #[must_use]
+    pub const fn new(digits: &'static str) -> Self {
+        let digits = digits.as_bytes();
+        assert!(digits.len() >= 2, "alphabet needs at least two digits");
+        let mut values = [INVALID; 128];
+        let mut i = 0;
+        while i < digits.len() {
+            let digit = digits[i] as usize;
+            assert!(digit < 128, "alphabet digits must be ASCII");
+            assert!(values[digit] == INVALID, "alphabet digits must be unique");
+            #[allow(clippy::cast_possible_truncation)] // At most 128 digits
+            {
+                values[digit] = i as u8;
+            }
+            i += 1;
+        }
+        Self { digits, values }
+    }
+
+    /// Makes parsing accept both upper and lower case letters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet contains the same letter in both cases.
+    #[must_use]
+    pub const fn case_insensitive(mut self) -> Self {
+        let mut i = 0;
+        while i < self.digits.len() {
+            let digit = self.digits[i];
+            if digit.is_ascii_alphabetic() {
+                let other = (digit ^ 0x20) as usize;
+                assert!(
+                    self.values[other] == INVALID,
+                    "case insensitive alphabet has letter in both cases"
+                );
+                self.values[other] = self.values[digit as usize];
+            }
+            i += 1;
+        }
+        self
+    }
+
+    /// The number of digits.
+    #[must_use]
+    pub const fn radix(&self) -> u64 {
+        self.digits.len() as u64
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // Digits are less than radix
+    fn digit(&self, value: u64) -> char {
+        char::from(self.digits[value as usize])
+    }
+
+    fn value(&self, c: char) -> Option<u64> {
+        let value = *self.values.get(c as usize)?;
+        (value != INVALID).then_some(u64::from(value))
+    }
+
+    fn encode(&self, digits: &[u64]) -> String {
+        if digits.is_empty() {
+            return self.digit(0).to_string();
+        }
+        digits.iter().map(|&digit| self.digit(digit)).collect()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Formats the [`Uint`] using the digits of `alphabet`.
+    ///
+    /// Zero is the single digit for zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Alphabet, Uint, uint};
+    /// # uint!{
+    /// assert_eq!(255_U64.to_string_alphabet(&Alphabet::BASE58), "5Q");
+    /// assert_eq!(255_U64.to_string_alphabet(&Alphabet::BASE32), "H7");
+    /// assert_eq!(0_U64.to_string_alphabet(&Alphabet::BASE36), "0");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_string_alphabet(&self, alphabet: &Alphabet) -> String {
+        alphabet.encode(&limbs_to_digits_be(self.limbs, alphabet.radix()))
+    }
+
+    /// Parses a string of digits of `alphabet`.
+    ///
+    /// Leading zero digits are allowed and the empty string is zero.
+    ///
+    /// # Errors
+    ///
+    /// * [`ParseErrorKind::InvalidDigit`] if the string contains a non-digit.
+    /// * [`ParseErrorKind::InputTooLong`] if there are more digits than in
+    ///   [`Uint::MAX`], not counting leading zeros.
+    /// * [`ParseErrorKind::Overflow`] if the value is too large.
+    pub fn from_str_alphabet(src: &str, alphabet: &Alphabet) -> Result<Self, ParseError> {
+        let radix = alphabet.radix();
+        let max = max_digits(BITS, radix);
+        let mut values = src
+            .char_indices()
+            .map(|(offset, c)| alphabet.value(c).ok_or((offset, c)))
+            .skip_while(|value| *value == Ok(0));
+        let digits = values
+            .by_ref()
+            .take(max)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|(offset, c)| {
+                ParseError::new(ParseErrorKind::InvalidDigit(c), offset, radix)
+            })?;
+        if let Some(value) = values.next() {
+            if let Err((offset, c)) = value {
+                return Err(ParseError::new(
+                    ParseErrorKind::InvalidDigit(c),
+                    offset,
+                    radix,
+                ));
+            }
+            return Err(ParseError::new(ParseErrorKind::InputTooLong(max), 0, radix));
+        }
+        Self::from_base_be(radix, digits)
+            .map_err(|_| ParseError::new(ParseErrorKind::Overflow, 0, radix))
+    }
+
+    /// Encodes the [`Uint`] in Bitcoin's base58check format.
+    ///
+    /// The payload is the [`Self::BYTES`] big-endian bytes followed by the
+    /// four bytes returned by `checksum`. Leading zero bytes of the payload
+    /// are encoded as `1`s. Bitcoin uses the first four bytes of the double
+    /// SHA-256 of the payload as checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// let sum = |bytes: &[u8]| [bytes.iter().fold(0, |a, b| a ^ b); 4];
+    /// assert_eq!(0x01_U16.to_base58check(sum), "17a29yz");
+    /// assert_eq!(Uint::from_base58check("17a29yz", sum), Ok(0x01_U16));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_base58check<F: FnOnce(&[u8]) -> [u8; 4]>(&self, checksum: F) -> String {
+        let mut payload = self.to_be_bytes_vec();
+        let check = checksum(&payload);
+        payload.extend_from_slice(&check);
+        let zeros = payload.iter().take_while(|&&byte| byte == 0).count();
+        let limbs: Vec<u64> = payload
+            .rchunks(8)
+            .map(|chunk| {
+                let mut limb = [0; 8];
+                limb[8 - chunk.len()..].copy_from_slice(chunk);
+                u64::from_be_bytes(limb)
+            })
+            .collect();
+        let digits = limbs_to_digits_be(limbs, 58);
+        let mut result = "1".repeat(zeros);
+        if !digits.is_empty() {
+            result.push_str(&Alphabet::BASE58.encode(&digits));
+        }
+        result
+    }
+
+    /// Decodes a value in Bitcoin's base58check format.
+    ///
+    /// The payload can have any length up to [`Self::BYTES`], but its value
+    /// must fit the [`Uint`]. See [`Self::to_base58check`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ParseErrorKind::InputTooLong`] if the string is longer than the
+    ///   encoding of [`Self::BYTES`] and four checksum bytes.
+    /// * [`ParseErrorKind::InvalidDigit`] if the string contains a non-digit.
+    /// * [`ParseErrorKind::InvalidChecksum`] if the checksum does not match.
+    /// * [`ParseErrorKind::Overflow`] if the value is too large.
+    pub fn from_base58check<F: FnOnce(&[u8]) -> [u8; 4]>(
+        src: &str,
+        checksum: F,
+    ) -> Result<Self, ParseError> {
+        let error = |kind, offset| ParseError::new(kind, offset, 58);
+        // Bounds the work before decoding. Digits are ASCII, so a longer input
+        // in bytes is either too long or has an invalid digit.
+        let max = max_digits(8 * (Self::BYTES + 4), 58);
+        if src.len() > max {
+            return Err(error(ParseErrorKind::InputTooLong(max), 0));
+        }
+        let zeros = src.chars().take_while(|&c| c == '1').count();
+        let digits = src[zeros..]
+            .char_indices()
+            .map(|(offset, c)| {
+                let kind = ParseErrorKind::InvalidDigit(c);
+                Alphabet::BASE58
+                    .value(c)
+                    .ok_or_else(|| error(kind, zeros + offset))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let limbs = limbs_from_digits_be(58, digits);
+        let mut payload = vec![0; zeros];
+        payload.extend(
+            limbs
+                .iter()
+                .rev()
+                .flat_map(|limb| limb.to_be_bytes())
+                .skip_while(|&byte| byte == 0),
+        );
+        if payload.len() < 4 {
+            return Err(error(ParseErrorKind::InvalidChecksum, 0));
+        }
+        let (payload, check) = payload.split_at(payload.len() - 4);
+        if checksum(payload) != check {
+            return Err(error(ParseErrorKind::InvalidChecksum, 0));
+        }
+        Self::try_from_be_slice(payload).ok_or_else(|| error(ParseErrorKind::Overflow, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U64, const_for, nlimbs};
+    use proptest::proptest;
+    use sha2::{Digest, Sha256};
+
+    fn double_sha256(bytes: &[u8]) -> [u8; 4] {
+        let hash = Sha256::digest(Sha256::digest(bytes));
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+
+    #[test]
+    fn test_base58check() {
+        // See <https://en.bitcoin.it/wiki/Technical_background_of_version_1_Bitcoin_addresses>
+        type Address = Uint<168, 3>;
+        let address = Address::try_from_be_slice(&[
+            0x00, 0xf5, 0x4a, 0x58, 0x51, 0xe9, 0x37, 0x2b, 0x87, 0x81, 0x0a, 0x8e, 0x60, 0xcd,
+            0xd2, 0xe7, 0xcf, 0xd8, 0x0b, 0x6e, 0x31,
+        ])
+        .unwrap();
+        let encoded = "1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAs";
+        assert_eq!(address.to_base58check(double_sha256), encoded);
+        assert_eq!(
+            Address::from_base58check(encoded, double_sha256),
+            Ok(address)
+        );
+        assert_eq!(
+            Address::from_base58check("1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAt", double_sha256),
+            Err(ParseError::new(ParseErrorKind::InvalidChecksum, 0, 58))
+        );
+        assert_eq!(
+            Address::from_base58check("1PMycacnJaSqwwJqjawXBErnLsZ7RkXUA0", double_sha256),
+            Err(ParseError::new(ParseErrorKind::InvalidDigit('0'), 33, 58))
+        );
+        assert_eq!(
+            Address::from_base58check("", double_sha256),
+            Err(ParseError::new(ParseErrorKind::InvalidChecksum, 0, 58))
+        );
+        assert_eq!(
+            Uint::<162, 3>::from_base58check(
+                &Address::MAX.to_base58check(double_sha256),
+                double_sha256
+            ),
+            Err(ParseError::new(ParseErrorKind::Overflow, 0, 58))
+        );
+        assert_eq!(
+            Uint::<128, 2>::from_base58check(encoded, double_sha256),
+            Err(ParseError::new(ParseErrorKind::InputTooLong(28), 0, 58))
+        );
+        // The payload is at most 21 bytes, plus 4 checksum bytes.
+        let max = max_digits(8 * 25, 58);
+        assert_eq!(
+            Address::from_base58check(&"1".repeat(max + 1), double_sha256),
+            Err(ParseError::new(ParseErrorKind::InputTooLong(max), 0, 58))
+        );
+        assert_eq!(
+            Address::from_base58check(&"z".repeat(10_000), double_sha256),
+            Err(ParseError::new(ParseErrorKind::InputTooLong(max), 0, 58))
+        );
+    }
+
+    #[test]
+    fn test_base58check_bound() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            for value in [U::ZERO, U::MAX] {
+                let encoded = value.to_base58check(double_sha256);
+                assert_eq!(U::from_base58check(&encoded, double_sha256), Ok(value));
+            }
+        });
+    }
+
+    #[test]
+    fn test_alphabet() {
+        assert_eq!(Alphabet::BASE58.radix(), 58);
+        assert_eq!(Alphabet::BASE32.radix(), 32);
+        assert_eq!(Alphabet::BASE36.radix(), 36);
+        assert_eq!(
+            U64::MAX.to_string_alphabet(&Alphabet::BASE36),
+            U64::MAX.to_string_radix(36)
+        );
+        assert_eq!(
+            U64::from_str_alphabet("3W5E11264SGSF", &Alphabet::BASE36),
+            Ok(U64::MAX)
+        );
+        assert_eq!(
+            U64::from_str_alphabet("h7", &Alphabet::BASE32),
+            Ok(U64::from(255))
+        );
+        assert_eq!(
+            U64::from_str_alphabet("5q", &Alphabet::BASE58),
+            Ok(U64::from(280))
+        );
+        assert_eq!(
+            U64::from_str_alphabet("0", &Alphabet::BASE58),
+            Err(ParseError::new(ParseErrorKind::InvalidDigit('0'), 0, 58))
+        );
+        assert_eq!(
+            U64::from_str_alphabet("2é", &Alphabet::BASE58),
+            Err(ParseError::new(ParseErrorKind::InvalidDigit('é'), 1, 58))
+        );
+    }
+
+    #[test]
+    fn test_alphabet_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                for alphabet in [Alphabet::BASE32, Alphabet::BASE36, Alphabet::BASE58] {
+                    let string = value.to_string_alphabet(&alphabet);
+                    assert_eq!(U::from_str_alphabet(&string, &alphabet), Ok(value));
+                }
+                let string = value.to_base58check(double_sha256);
+                assert_eq!(U::from_base58check(&string, double_sha256), Ok(value));
+            });
+        });
+    }
+}
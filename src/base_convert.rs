@@ -1,4 +1,9 @@
-use crate::Uint;
+use crate::{
+    algorithms::{div_rem, divrem_nby1},
+    string::max_digits,
+    Uint,
+};
+use core::{cell::OnceCell, cmp::Ordering};
 use thiserror::Error;
 
 /// Error for [`from_base_le`][Uint::from_base_le] and
@@ -91,6 +96,26 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         if base < 2 {
             return Err(BaseConvertError::InvalidBase(base));
         }
+        if LIMBS > PARSE_THRESHOLD {
+            // Leading zeros are skipped and any more than `max_digits` can not
+            // fit, so the buffer stays bounded for long or endless inputs.
+            let max = max_digits(BITS, base);
+            let mut buffer = Vec::new();
+            for digit in digits.into_iter().skip_while(|&digit| digit == 0) {
+                if buffer.len() == max {
+                    return Err(BaseConvertError::Overflow);
+                }
+                if digit >= base {
+                    return Err(BaseConvertError::InvalidDigit(digit, base));
+                }
+                buffer.push(digit);
+            }
+            let limbs = limbs_from_digits_be(base, buffer);
+            return match Self::overflowing_from_limbs_slice(&limbs) {
+                (result, false) => Ok(result),
+                (_, true) => Err(BaseConvertError::Overflow),
+            };
+        }
         let mut result = Self::ZERO;
         for digit in digits {
             if digit >= base {
@@ -105,7 +130,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
                 *limb = carry as u64;
                 carry >>= 64;
             }
-            if carry > 0 {
+            if carry > 0 || (LIMBS > 0 && result.limbs[LIMBS - 1] > Self::MASK) {
                 return Err(BaseConvertError::Overflow);
             }
         }
@@ -113,12 +138,469 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     }
 }
 
-struct SpigotLittle<const LIMBS: usize> {
+/// Number of limbs above which conversions to digits divide and conquer.
+///
+/// Below it, dividing by one chunk of digits at a time is faster.
+pub const DC_THRESHOLD: usize = 24;
+
+/// Number of limbs above which [`Uint::from_base_be`] buffers the digits and
+/// converts them in chunks with [`limbs_from_digits_be`].
+const PARSE_THRESHOLD: usize = 8;
+
+/// Number of digit chunks above which [`limbs_from_chunks`] divides and
+/// conquers.
+///
+/// Multiplying in one chunk at a time is cheap, so the recursion only pays off
+/// once that no longer fits the cache. Tests use a small value to cover both
+/// paths.
+const FROM_DC_THRESHOLD: usize = if cfg!(test) { 8 } else { 4096 };
+
+/// Number of limbs above which [`mul_karatsuba`] splits the operands.
+const KARATSUBA_THRESHOLD: usize = 24;
+
+/// Number of limbs above which dividing by a power of the base uses a
+/// reciprocal. Tests use a small value to cover both paths.
+const RECIPROCAL_THRESHOLD: usize = if cfg!(test) { 16 } else { 1024 };
+
+/// Returns the base `base` digits of the little-endian `limbs` in big-endian
+/// order. Leading zeros are omitted, so zero has no digits.
+pub fn limbs_to_digits_be<L: AsRef<[u64]>>(limbs: L, base: u64) -> Vec<u64> {
+    let (chunk_base, chunk_len) = chunk_base(base);
+    let mut digits = Vec::new();
+    for mut chunk in limbs_to_chunks(limbs.as_ref(), chunk_base) {
+        for _ in 0..chunk_len {
+            digits.push(chunk % base);
+            chunk /= base;
+        }
+    }
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    digits.reverse();
+    digits
+}
+
+/// Returns the little-endian limbs of the number with base `base` digits
+/// `digits` in big-endian order. The digits must be less than `base`.
+pub fn limbs_from_digits_be<I: IntoIterator<Item = u64>>(base: u64, digits: I) -> Vec<u64> {
+    let (chunk_base, chunk_len) = chunk_base(base);
+    let digits: Vec<u64> = digits.into_iter().collect();
+    let chunks: Vec<u64> = digits
+        .rchunks(chunk_len)
+        .map(|chunk| {
+            chunk.iter().fold(0, |chunk, &digit| {
+                debug_assert!(digit < base);
+                chunk * base + digit
+            })
+        })
+        .collect();
+    limbs_from_chunks(&chunks, chunk_base)
+}
+
+/// Returns the largest power of `base` that fits a `u64` and its exponent.
+const fn chunk_base(base: u64) -> (u64, usize) {
+    let mut chunk_base = base;
+    let mut chunk_len = 1;
+    while let Some(next) = chunk_base.checked_mul(base) {
+        chunk_base = next;
+        chunk_len += 1;
+    }
+    (chunk_base, chunk_len)
+}
+
+/// Returns the base `base` digits of the little-endian `limbs` in
+/// little-endian order. Leading zeros are omitted, so zero has no digits.
+///
+/// Above [`DC_THRESHOLD`] limbs the number is split recursively by dividing
+/// by precomputed powers `base^(2^k)`. Above [`RECIPROCAL_THRESHOLD`] limbs
+/// the divisions multiply by a reciprocal instead, so with [`mul_karatsuba`]
+/// the conversion is subquadratic.
+pub fn limbs_to_chunks(limbs: &[u64], base: u64) -> Vec<u64> {
+    debug_assert!(base > 1);
+    let limbs = trim(limbs);
+    let mut powers = vec![Power::new(vec![base])];
+    if limbs.len() > DC_THRESHOLD {
+        // Until the number is less than the square of the largest power.
+        while 2 * bit_len(&powers.last().unwrap().limbs) - 2 < bit_len(limbs) {
+            let square = square(&powers.last().unwrap().limbs);
+            powers.push(Power::new(square));
+        }
+    } else {
+        powers.clear();
+    }
+    // Without powers the spigot needs room for all digits.
+    let len = if powers.is_empty() {
+        64 * limbs.len() / base.ilog2() as usize + 1
+    } else {
+        1 << powers.len()
+    };
+    let mut chunks = vec![0; len];
+    to_chunks_rec(limbs, base, &powers, &mut chunks);
+    while chunks.last() == Some(&0) {
+        chunks.pop();
+    }
+    chunks
+}
+
+/// Writes the digits of `limbs` in `chunks`, where `limbs` is less than
+/// `base^chunks.len()` and `chunks.len()` is `2^powers.len()` if there are
+/// powers.
+fn to_chunks_rec(limbs: &[u64], base: u64, powers: &[Power], chunks: &mut [u64]) {
+    let limbs = trim(limbs);
+    match powers.split_last() {
+        Some((power, powers)) if limbs.len() > DC_THRESHOLD => {
+            let (low, high) = chunks.split_at_mut(chunks.len() / 2);
+            if limbs.len() < power.limbs.len() {
+                high.fill(0);
+                to_chunks_rec(limbs, base, powers, low);
+            } else {
+                let (quotient, remainder) = power.div_rem(limbs);
+                to_chunks_rec(&remainder, base, powers, low);
+                to_chunks_rec(&quotient, base, powers, high);
+            }
+        }
+        _ => {
+            let mut limbs = limbs.to_vec();
+            let mut len = limbs.len();
+            for chunk in chunks {
+                if len == 0 {
+                    *chunk = 0;
+                    continue;
+                }
+                *chunk = divrem_nby1(&mut limbs[..len], base);
+                len = trim(&limbs[..len]).len();
+            }
+            debug_assert_eq!(len, 0);
+        }
+    }
+}
+
+/// Returns the little-endian limbs of the number with base `base` digits
+/// `chunks` in little-endian order. Leading zero limbs are omitted.
+///
+/// Above [`FROM_DC_THRESHOLD`] chunks the halves are converted recursively and
+/// combined by multiplying with precomputed powers `base^(2^k)`.
+pub fn limbs_from_chunks(chunks: &[u64], base: u64) -> Vec<u64> {
+    debug_assert!(base > 1);
+    let mut powers = Vec::new();
+    if chunks.len() > FROM_DC_THRESHOLD {
+        powers.push(vec![base]);
+        // Until the chunks split in halves at the largest power.
+        while 2 << (powers.len() - 1) < chunks.len() {
+            let square = square(powers.last().unwrap());
+            powers.push(square);
+        }
+    }
+    from_chunks_rec(chunks, base, &powers)
+}
+
+fn from_chunks_rec(chunks: &[u64], base: u64, powers: &[Vec<u64>]) -> Vec<u64> {
+    match powers.split_last() {
+        Some((power, powers)) if chunks.len() > FROM_DC_THRESHOLD => {
+            let half = 1 << powers.len();
+            if chunks.len() <= half {
+                return from_chunks_rec(chunks, base, powers);
+            }
+            let (low, high) = chunks.split_at(half);
+            let low = from_chunks_rec(low, base, powers);
+            let high = from_chunks_rec(high, base, powers);
+            let mut result = mul_karatsuba(&high, power);
+            add_assign(&mut result, &low);
+            result
+        }
+        _ => {
+            let mut limbs = Vec::new();
+            for &chunk in chunks.iter().rev() {
+                debug_assert!(chunk < base);
+                let mut carry: u128 = u128::from(chunk);
+                #[allow(clippy::cast_possible_truncation)]
+                for limb in &mut limbs {
+                    carry += u128::from(*limb) * u128::from(base);
+                    *limb = carry as u64;
+                    carry >>= 64;
+                }
+                if carry > 0 {
+                    #[allow(clippy::cast_possible_truncation)]
+                    limbs.push(carry as u64);
+                }
+            }
+            limbs
+        }
+    }
+}
+
+/// A power of the base that [`to_chunks_rec`] divides by.
+///
+/// When both the power and the quotient have more than
+/// [`RECIPROCAL_THRESHOLD`] limbs it divides by Barrett reduction instead of
+/// Knuth's algorithm D. The reciprocal `floor(2^(2m) / limbs)`, where `m` is
+/// the number of bits, is computed on first use.
+struct Power {
+    limbs:      Vec<u64>,
+    reciprocal: OnceCell<Vec<u64>>,
+}
+
+impl Power {
+    const fn new(limbs: Vec<u64>) -> Self {
+        Self {
+            limbs,
+            reciprocal: OnceCell::new(),
+        }
+    }
+
+    /// Returns the quotient and remainder of `numerator`, which must be less
+    /// than the square of the power.
+    fn div_rem(&self, numerator: &[u64]) -> (Vec<u64>, Vec<u64>) {
+        let numerator = trim(numerator);
+        if numerator.len() < self.limbs.len() {
+            return (Vec::new(), numerator.to_vec());
+        }
+        let quotient_len = numerator.len() - self.limbs.len() + 1;
+        if self.limbs.len().min(quotient_len) <= RECIPROCAL_THRESHOLD {
+            let mut quotient = numerator.to_vec();
+            let mut remainder = self.limbs.clone();
+            div_rem(&mut quotient, &mut remainder);
+            return (trim(&quotient).to_vec(), trim(&remainder).to_vec());
+        }
+        // With an exact reciprocal the estimate is at most two less than the
+        // quotient, see Handbook of Applied Cryptography, algorithm 14.42.
+        // Each unit the reciprocal is short adds at most one more.
+        let reciprocal = self.reciprocal.get_or_init(|| reciprocal(&self.limbs));
+        let bits = bit_len(&self.limbs);
+        let estimate = mul_karatsuba(&shr(numerator, bits - 1), reciprocal);
+        let mut quotient = shr(&estimate, bits + 1);
+        let mut remainder = numerator.to_vec();
+        sub_assign(&mut remainder, &mul_karatsuba(&quotient, &self.limbs));
+        while compare(&remainder, &self.limbs) != Ordering::Less {
+            sub_assign(&mut remainder, &self.limbs);
+            add_assign(&mut quotient, &[1]);
+        }
+        (quotient, remainder)
+    }
+}
+
+/// Returns at most `floor(2^(2m) / divisor)` and at most three less, where
+/// `m` is the number of bits of the trimmed non-empty `divisor`.
+///
+/// Up to [`RECIPROCAL_THRESHOLD`] limbs this is exact. Above it takes a Newton
+/// step from the reciprocal of the top half of the bits, so it costs a few
+/// multiplications.
+fn reciprocal(divisor: &[u64]) -> Vec<u64> {
+    let bits = bit_len(divisor);
+    if divisor.len() <= RECIPROCAL_THRESHOLD {
+        let mut quotient = shl(&[1], 2 * bits);
+        let mut remainder = divisor.to_vec();
+        div_rem(&mut quotient, &mut remainder);
+        return trim(&quotient).to_vec();
+    }
+    // Rounding the top bits up makes the approximation `x = top * 2^shift`
+    // at most the reciprocal, with a relative error of about `2^-half`. The
+    // step `x + x * (1 - x * divisor / 2^(2m))` stays at most the reciprocal
+    // and squares the error, which leaves a few units from rounding down.
+    let half = bits / 2 + 32;
+    let shift = bits - half;
+    let mut top = shr(divisor, shift);
+    add_assign(&mut top, &[1]);
+    let top = shr(&reciprocal(&top), 2 * (bit_len(&top) - half));
+    let mut error = shl(&[1], 2 * bits - shift);
+    sub_assign(&mut error, &mul_karatsuba(&top, divisor));
+    let mut result = shl(&top, shift);
+    add_assign(
+        &mut result,
+        &shr(&mul_karatsuba(&top, &error), 2 * bits - 2 * shift),
+    );
+    result
+}
+
+/// Returns `lhs * rhs` without leading zero limbs.
+///
+/// Above [`KARATSUBA_THRESHOLD`] limbs it uses Karatsuba multiplication,
+/// which takes `O(n^1.58)` instead of `O(n^2)` limb products.
+fn mul_karatsuba(lhs: &[u64], rhs: &[u64]) -> Vec<u64> {
+    let (lhs, rhs) = (trim(lhs), trim(rhs));
+    let (long, short) = if lhs.len() >= rhs.len() {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+    let mut result = vec![0; long.len() + short.len()];
+    if short.len() <= KARATSUBA_THRESHOLD {
+        mul_basecase(long, short, &mut result);
+    } else if 2 * short.len() <= long.len() {
+        // Multiply by pieces of the long side that balance the short side.
+        for (index, piece) in long.chunks(short.len()).enumerate() {
+            add_at(
+                &mut result,
+                &mul_karatsuba(piece, short),
+                index * short.len(),
+            );
+        }
+    } else {
+        // With `x = x1 * B + x0` and `y = y1 * B + y0` the middle term
+        // `x1 * y0 + x0 * y1` is `(x1 + x0) * (y1 + y0) - x1 * y1 - x0 * y0`.
+        let half = long.len().div_ceil(2);
+        let (long_low, long_high) = long.split_at(half);
+        let (short_low, short_high) = short.split_at(half);
+        let low = mul_karatsuba(long_low, short_low);
+        let high = mul_karatsuba(long_high, short_high);
+        let mut long_sum = long_low.to_vec();
+        add_assign(&mut long_sum, long_high);
+        let mut short_sum = short_low.to_vec();
+        add_assign(&mut short_sum, short_high);
+        let mut middle = mul_karatsuba(&long_sum, &short_sum);
+        sub_assign(&mut middle, &low);
+        sub_assign(&mut middle, &high);
+        add_at(&mut result, &low, 0);
+        add_at(&mut result, &middle, half);
+        add_at(&mut result, &high, 2 * half);
+    }
+    let len = trim(&result).len();
+    result.truncate(len);
+    result
+}
+
+/// Sets the zeroed `result` to `lhs * rhs` by schoolbook multiplication.
+///
+/// Unlike [`mul`](crate::algorithms::mul) it assumes the result fits, which
+/// keeps the inner loop short.
+#[allow(clippy::cast_possible_truncation)]
+fn mul_basecase(lhs: &[u64], rhs: &[u64], result: &mut [u64]) {
+    debug_assert!(result.len() >= lhs.len() + rhs.len());
+    for (index, &left) in lhs.iter().enumerate() {
+        let mut carry = 0;
+        for (limb, &right) in result[index..index + rhs.len()].iter_mut().zip(rhs) {
+            let product =
+                u128::from(left) * u128::from(right) + u128::from(*limb) + u128::from(carry);
+            *limb = product as u64;
+            carry = (product >> 64) as u64;
+        }
+        result[index + rhs.len()] = carry;
+    }
+}
+
+/// Adds `value` shifted by `offset` limbs to `result`, which must not
+/// overflow.
+fn add_at(result: &mut [u64], value: &[u64], offset: usize) {
+    let mut carry = false;
+    let mut index = offset;
+    for &limb in trim(value) {
+        let (sum, overflow) = result[index].overflowing_add(limb);
+        let (sum, carry_overflow) = sum.overflowing_add(u64::from(carry));
+        result[index] = sum;
+        carry = overflow || carry_overflow;
+        index += 1;
+    }
+    while carry {
+        let (sum, overflow) = result[index].overflowing_add(1);
+        result[index] = sum;
+        carry = overflow;
+        index += 1;
+    }
+}
+
+/// Adds `rhs` to `lhs` and removes the leading zero limbs.
+fn add_assign(lhs: &mut Vec<u64>, rhs: &[u64]) {
+    lhs.resize(lhs.len().max(rhs.len()) + 1, 0);
+    add_at(lhs, rhs, 0);
+    let len = trim(lhs).len();
+    lhs.truncate(len);
+}
+
+/// Subtracts `rhs` from `lhs`, which must not be less, and removes the
+/// leading zero limbs.
+fn sub_assign(lhs: &mut Vec<u64>, rhs: &[u64]) {
+    let mut borrow = false;
+    let mut index = 0;
+    for &limb in trim(rhs) {
+        let (difference, overflow) = lhs[index].overflowing_sub(limb);
+        let (difference, borrow_overflow) = difference.overflowing_sub(u64::from(borrow));
+        lhs[index] = difference;
+        borrow = overflow || borrow_overflow;
+        index += 1;
+    }
+    while borrow {
+        let (difference, overflow) = lhs[index].overflowing_sub(1);
+        lhs[index] = difference;
+        borrow = overflow;
+        index += 1;
+    }
+    let len = trim(lhs).len();
+    lhs.truncate(len);
+}
+
+/// Compares the values of `lhs` and `rhs`.
+fn compare(lhs: &[u64], rhs: &[u64]) -> Ordering {
+    let (lhs, rhs) = (trim(lhs), trim(rhs));
+    lhs.len()
+        .cmp(&rhs.len())
+        .then_with(|| lhs.iter().rev().cmp(rhs.iter().rev()))
+}
+
+/// Returns `limbs * 2^bits`.
+fn shl(limbs: &[u64], bits: usize) -> Vec<u64> {
+    let (limb_shift, bit_shift) = (bits / 64, bits % 64);
+    let mut result = vec![0; limb_shift];
+    result.extend_from_slice(trim(limbs));
+    result.push(0);
+    if bit_shift > 0 {
+        for index in (limb_shift + 1..result.len()).rev() {
+            result[index] = (result[index] << bit_shift) | (result[index - 1] >> (64 - bit_shift));
+        }
+        result[limb_shift] <<= bit_shift;
+    }
+    let len = trim(&result).len();
+    result.truncate(len);
+    result
+}
+
+/// Returns `limbs / 2^bits`.
+fn shr(limbs: &[u64], bits: usize) -> Vec<u64> {
+    let (limb_shift, bit_shift) = (bits / 64, bits % 64);
+    let limbs = trim(limbs);
+    if limb_shift >= limbs.len() {
+        return Vec::new();
+    }
+    let mut result = limbs[limb_shift..].to_vec();
+    if bit_shift > 0 {
+        for index in 0..result.len() {
+            let next = result
+                .get(index + 1)
+                .map_or(0, |&limb| limb << (64 - bit_shift));
+            result[index] = (result[index] >> bit_shift) | next;
+        }
+    }
+    let len = trim(&result).len();
+    result.truncate(len);
+    result
+}
+
+/// Removes the leading zero limbs.
+fn trim(limbs: &[u64]) -> &[u64] {
+    let len = limbs
+        .iter()
+        .rposition(|&limb| limb != 0)
+        .map_or(0, |i| i + 1);
+    &limbs[..len]
+}
+
+/// Number of significant bits of trimmed non-empty `limbs`.
+fn bit_len(limbs: &[u64]) -> usize {
+    64 * limbs.len()
+        - limbs
+            .last()
+            .map_or(64, |limb| limb.leading_zeros() as usize)
+}
+
+fn square(limbs: &[u64]) -> Vec<u64> {
+    mul_karatsuba(limbs, limbs)
+}
+
+struct SpigotLittle<L> {
     base:  u64,
-    limbs: [u64; LIMBS],
+    limbs: L,
 }
 
-impl<const LIMBS: usize> Iterator for SpigotLittle<LIMBS> {
+impl<L: AsMut<[u64]>> Iterator for SpigotLittle<L> {
     type Item = u64;
 
     #[allow(clippy::cast_possible_truncation)] // Doesn't truncate
@@ -127,7 +609,7 @@ impl<const LIMBS: usize> Iterator for SpigotLittle<LIMBS> {
         let mut zero: u64 = 0_u64;
         let mut remainder = 0_u128;
         // OPT: If we keep track of leading zero limbs we can half iterations.
-        for limb in self.limbs.iter_mut().rev() {
+        for limb in self.limbs.as_mut().iter_mut().rev() {
             zero |= *limb;
             remainder <<= 64;
             remainder |= u128::from(*limb);
@@ -159,6 +641,12 @@ impl Iterator for OwnedVecIterator {
 #[allow(clippy::zero_prefixed_literal)]
 mod tests {
     use super::*;
+    use crate::algorithms::mul;
+    use proptest::{
+        collection::vec,
+        prelude::{any, prop_assume, prop_oneof, proptest, Just},
+        sample::select,
+    };
 
     // 90630363884335538722706632492458228784305343302099024356772372330524102404852
     const N: Uint<256, 4> = Uint::from_limbs([
@@ -206,4 +694,126 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_chunks_dc() {
+        let bases = [10_000_000_000_000_000_000, 1 << 63, 58_u64.pow(10), 7, 2];
+        proptest!(|(limbs in vec(any::<u64>(), 0..300), base in select(bases.to_vec()))| {
+            let chunks = limbs_to_chunks(&limbs, base);
+            let spigot: Vec<u64> = SpigotLittle { base, limbs: limbs.clone() }.collect();
+            let mut expected = spigot;
+            while expected.last() == Some(&0) {
+                expected.pop();
+            }
+            assert_eq!(chunks, expected);
+            assert_eq!(limbs_from_chunks(&chunks, base), trim(&limbs));
+        });
+    }
+
+    #[test]
+    fn test_mul_karatsuba() {
+        let limb = prop_oneof![any::<u64>(), Just(0), Just(u64::MAX)];
+        proptest!(|(lhs in vec(limb.clone(), 0..200), rhs in vec(limb, 0..200))| {
+            let mut expected = vec![0; lhs.len() + rhs.len()];
+            mul(&lhs, &rhs, &mut expected);
+            assert_eq!(mul_karatsuba(&lhs, &rhs), trim(&expected));
+        });
+        let ones = vec![u64::MAX; 100];
+        let mut expected = vec![0; 200];
+        mul(&ones, &ones, &mut expected);
+        assert_eq!(mul_karatsuba(&ones, &ones), expected);
+    }
+
+    fn assert_reciprocal(divisor: &[u64]) {
+        let mut expected = shl(&[1], 2 * bit_len(divisor));
+        let mut remainder = divisor.to_vec();
+        div_rem(&mut expected, &mut remainder);
+        let mut expected = trim(&expected).to_vec();
+        sub_assign(&mut expected, &reciprocal(divisor));
+        assert!(compare(&expected, &[3]) != Ordering::Greater);
+    }
+
+    #[test]
+    fn test_power_div_rem() {
+        let limb = prop_oneof![any::<u64>(), Just(0), Just(u64::MAX)];
+        proptest!(|(
+            divisor in vec(limb.clone(), 1..150),
+            numerator in vec(limb, 0..300),
+        )| {
+            let divisor = trim(&divisor).to_vec();
+            prop_assume!(!divisor.is_empty());
+            // Reduce the numerator below the square of the divisor.
+            let mut numerator = numerator;
+            numerator.truncate(2 * divisor.len());
+            if let Some(top) = numerator.get_mut(2 * divisor.len() - 1) {
+                *top = 0;
+            }
+            let numerator = trim(&numerator);
+
+            let power = Power::new(divisor.clone());
+            let mut quotient = numerator.to_vec();
+            let mut remainder = divisor.clone();
+            if numerator.len() >= divisor.len() {
+                div_rem(&mut quotient, &mut remainder);
+            } else {
+                quotient.clear();
+                remainder = numerator.to_vec();
+            }
+            let expected = (trim(&quotient).to_vec(), trim(&remainder).to_vec());
+            assert_eq!(power.div_rem(numerator), expected);
+
+            assert_reciprocal(&divisor);
+        });
+        for bits in [3200, 3263, 3264, 6400] {
+            let power_of_two = shl(&[1], bits);
+            let mut below = power_of_two.clone();
+            sub_assign(&mut below, &[1]);
+            for divisor in [power_of_two, below] {
+                assert_reciprocal(&divisor);
+            }
+        }
+    }
+
+    #[test]
+    fn test_digits_dc() {
+        type U = Uint<8192, 128>;
+        proptest!(|(value: U)| {
+            let digits = limbs_to_digits_be(value.as_limbs(), 10);
+            let expected: Vec<u64> = value.to_base_be(10).collect();
+            assert_eq!(digits, expected);
+            assert_eq!(U::from_base_be(10, digits), Ok(value));
+            assert_eq!(U::from_str_radix(&value.to_string(), 10), Ok(value));
+        });
+        assert_eq!(
+            U::from_base_be(10, [1, 10]),
+            Err(BaseConvertError::InvalidDigit(10, 10))
+        );
+        let digits = U::MAX.to_base_be(10).chain([0]);
+        assert_eq!(U::from_base_be(10, digits), Err(BaseConvertError::Overflow));
+        let digits = [0; 1000].into_iter().chain(U::MAX.to_base_be(10));
+        assert_eq!(U::from_base_be(10, digits), Ok(U::MAX));
+        let endless = core::iter::repeat(1);
+        assert_eq!(
+            U::from_base_be(10, endless),
+            Err(BaseConvertError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_from_base_be_overflow() {
+        assert_eq!(
+            Uint::<8, 1>::from_base_be(10, [2, 5, 5]),
+            Ok(Uint::from(255))
+        );
+        assert_eq!(
+            Uint::<8, 1>::from_base_be(10, [2, 5, 6]),
+            Err(BaseConvertError::Overflow)
+        );
+        assert_eq!(Uint::<1, 1>::from_base_be(10, [1]), Ok(Uint::from(1)));
+        assert_eq!(
+            Uint::<1, 1>::from_base_be(10, [5]),
+            Err(BaseConvertError::Overflow)
+        );
+        assert_eq!(Uint::<0, 0>::from_base_be(10, [0]), Ok(Uint::ZERO));
+    }
 }
@@ -328,7 +328,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         for i in 0..limbs {
             overflow |= self.limbs[i] != 0;
         }
-        overflow |= self.limbs[limbs] >> bits != 0;
+        overflow |= self.limbs[limbs] << (64 - bits) != 0;
 
         // Shift
         for i in 0..(LIMBS - limbs - 1) {
@@ -656,6 +656,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_checked_shr() {
+        type U = Uint<128, 2>;
+        assert_eq!(U::from(4).checked_shr(2), Some(U::from(1)));
+        assert_eq!(U::from(5).checked_shr(2), None);
+        assert_eq!(
+            U::from(1_u128 << 100).checked_shr(70),
+            Some(U::from(1 << 30))
+        );
+        assert_eq!(U::from(3_u128 << 99).checked_shr(100), None);
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U, shift in 0..=BITS + 2)| {
+                let (shifted, inexact) = value.overflowing_shr(shift);
+                assert_eq!(inexact, shifted << shift != value);
+            });
+        });
+    }
+
     #[test]
     #[allow(clippy::cast_lossless)]
     #[allow(clippy::cast_possible_truncation)]
@@ -0,0 +1,238 @@
+use crate::Uint;
+use thiserror::Error;
+
+/// Error for [`Uint::from_der_integer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum DerError {
+    /// The identifier octet is not the universal `INTEGER` tag `0x02`.
+    #[error("expected DER INTEGER tag 0x02, found {0:#04x}")]
+    UnexpectedTag(u8),
+
+    /// The input ended before the end of the encoding.
+    #[error("DER encoding is truncated")]
+    Truncated,
+
+    /// There are bytes left after the encoding.
+    #[error("DER encoding has trailing bytes")]
+    TrailingBytes,
+
+    /// The length or the value is not in the minimal encoding.
+    #[error("DER encoding is not minimal")]
+    NonCanonical,
+
+    /// The value is a negative integer.
+    #[error("DER INTEGER is negative")]
+    Negative,
+
+    /// The value is too large to fit the target type.
+    ///
+    /// `.0` is `BITS`.
+    #[error("DER INTEGER is too large for Uint<{0}>")]
+    Overflow(usize),
+}
+
+/// Universal tag of the ASN.1 `INTEGER` type.
+const INTEGER: u8 = 0x02;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Encodes the [`Uint`] as an ASN.1 DER `INTEGER`, including the tag and
+    /// length octets.
+    ///
+    /// The content is the minimal big-endian two's complement encoding, so a
+    /// zero byte is prepended when the most significant bit is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// assert_eq!(0_U64.to_der_integer(), [0x02, 0x01, 0x00]);
+    /// assert_eq!(0x7f_U64.to_der_integer(), [0x02, 0x01, 0x7f]);
+    /// assert_eq!(0x80_U64.to_der_integer(), [0x02, 0x02, 0x00, 0x80]);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_der_integer(&self) -> Vec<u8> {
+        let content = self.der_integer_content();
+        let mut result = Vec::with_capacity(content.len() + 10);
+        result.push(INTEGER);
+        push_der_length(&mut result, content.len());
+        result.extend_from_slice(&content);
+        result
+    }
+
+    /// Decodes an ASN.1 DER `INTEGER`, including the tag and length octets.
+    ///
+    /// # Errors
+    ///
+    /// * [`DerError::UnexpectedTag`] if the tag is not `INTEGER`.
+    /// * [`DerError::Truncated`] if the input is shorter than the encoding.
+    /// * [`DerError::TrailingBytes`] if the input is longer than the encoding.
+    /// * [`DerError::NonCanonical`] if the length or the value has redundant
+    ///   leading bytes, or the value is empty.
+    /// * [`DerError::Negative`] if the value is a negative integer.
+    /// * [`DerError::Overflow`] if the value does not fit the [`Uint`].
+    pub fn from_der_integer(bytes: &[u8]) -> Result<Self, DerError> {
+        let (&tag, rest) = bytes.split_first().ok_or(DerError::Truncated)?;
+        if tag != INTEGER {
+            return Err(DerError::UnexpectedTag(tag));
+        }
+        let (length, rest) = split_der_length(rest)?;
+        if rest.len() < length {
+            return Err(DerError::Truncated);
+        }
+        if rest.len() > length {
+            return Err(DerError::TrailingBytes);
+        }
+        Self::from_der_integer_content(rest)
+    }
+
+    /// Encodes the content octets of a DER `INTEGER`.
+    pub(crate) fn der_integer_content(&self) -> Vec<u8> {
+        let mut content = self.to_be_bytes_trimmed_vec();
+        if !matches!(content.first(), Some(0x00..=0x7f)) {
+            content.insert(0, 0);
+        }
+        content
+    }
+
+    /// Decodes the content octets of a DER `INTEGER`.
+    pub(crate) fn from_der_integer_content(content: &[u8]) -> Result<Self, DerError> {
+        match content {
+            [] | [0x00, 0x00..=0x7f, ..] | [0xff, 0x80..=0xff, ..] => Err(DerError::NonCanonical),
+            [0x80..=0xff, ..] => Err(DerError::Negative),
+            _ => Self::try_from_be_slice(content).ok_or(DerError::Overflow(BITS)),
+        }
+    }
+}
+
+/// Appends a definite length in the minimal encoding.
+#[allow(clippy::cast_possible_truncation)] // Intentional
+fn push_der_length(bytes: &mut Vec<u8>, length: usize) {
+    if length < 0x80 {
+        bytes.push(length as u8);
+    } else {
+        let octets = length.to_be_bytes();
+        let skip = octets.iter().take_while(|&&byte| byte == 0).count();
+        bytes.push(0x80 | (octets.len() - skip) as u8);
+        bytes.extend_from_slice(&octets[skip..]);
+    }
+}
+
+/// Splits a minimally encoded definite length from the start of `bytes`.
+fn split_der_length(bytes: &[u8]) -> Result<(usize, &[u8]), DerError> {
+    let (&first, rest) = bytes.split_first().ok_or(DerError::Truncated)?;
+    if first < 0x80 {
+        return Ok((usize::from(first), rest));
+    }
+    let count = usize::from(first & 0x7f);
+    if rest.len() < count {
+        return Err(DerError::Truncated);
+    }
+    let (octets, rest) = rest.split_at(count);
+    // Rejects the indefinite form (count zero) and leading zero octets.
+    if matches!(octets.first(), None | Some(0)) {
+        return Err(DerError::NonCanonical);
+    }
+    // A length that does not fit a `usize` can never be satisfied.
+    if count > core::mem::size_of::<usize>() {
+        return Err(DerError::Truncated);
+    }
+    let length = octets
+        .iter()
+        .fold(0, |length, &byte| (length << 8) | usize::from(byte));
+    if length < 0x80 {
+        return Err(DerError::NonCanonical);
+    }
+    Ok((length, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases::{U256, U4096, U64, U8},
+        const_for, nlimbs,
+    };
+    use hex_literal::hex;
+    use proptest::proptest;
+
+    #[test]
+    fn test_der_integer() {
+        assert_eq!(U64::from(0).to_der_integer(), hex!("020100"));
+        assert_eq!(U64::from(127).to_der_integer(), hex!("02017f"));
+        assert_eq!(U64::from(128).to_der_integer(), hex!("02020080"));
+        assert_eq!(U64::from(256).to_der_integer(), hex!("02020100"));
+        assert_eq!(U64::MAX.to_der_integer(), hex!("020900ffffffffffffffff"));
+        let encoded = U4096::MAX.to_der_integer();
+        assert_eq!(encoded[..5], hex!("0282020100"));
+        assert_eq!(encoded.len(), 4 + 513);
+
+        let encoded = (U256::from(1) << 255).to_der_integer();
+        assert_eq!(encoded[..4], hex!("02210080"));
+        assert_eq!(U256::from_der_integer(&encoded), Ok(U256::from(1) << 255));
+    }
+
+    #[test]
+    fn test_from_der_integer() {
+        assert_eq!(U8::from_der_integer(&hex!("02020080")), Ok(U8::from(0x80)));
+        assert_eq!(U8::from_der_integer(&hex!("020100")), Ok(U8::ZERO));
+        assert_eq!(
+            U8::from_der_integer(&hex!("030100")),
+            Err(DerError::UnexpectedTag(3))
+        );
+        assert_eq!(U8::from_der_integer(&[]), Err(DerError::Truncated));
+        assert_eq!(
+            U8::from_der_integer(&hex!("0202ff")),
+            Err(DerError::Truncated)
+        );
+        assert_eq!(
+            U8::from_der_integer(&hex!("02010000")),
+            Err(DerError::TrailingBytes)
+        );
+        assert_eq!(
+            U8::from_der_integer(&hex!("0200")),
+            Err(DerError::NonCanonical)
+        );
+        assert_eq!(
+            U8::from_der_integer(&hex!("0202007f")),
+            Err(DerError::NonCanonical)
+        );
+        assert_eq!(
+            U8::from_der_integer(&hex!("0202ff80")),
+            Err(DerError::NonCanonical)
+        );
+        assert_eq!(
+            U8::from_der_integer(&hex!("02810100")),
+            Err(DerError::NonCanonical)
+        );
+        assert_eq!(
+            U8::from_der_integer(&hex!("028000")),
+            Err(DerError::NonCanonical)
+        );
+        assert_eq!(
+            U8::from_der_integer(&hex!("020180")),
+            Err(DerError::Negative)
+        );
+        assert_eq!(
+            U8::from_der_integer(&hex!("0201ff")),
+            Err(DerError::Negative)
+        );
+        assert_eq!(
+            U8::from_der_integer(&hex!("02020100")),
+            Err(DerError::Overflow(8))
+        );
+    }
+
+    #[test]
+    fn test_der_integer_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let encoded = value.to_der_integer();
+                assert_eq!(U::from_der_integer(&encoded), Ok(value));
+            });
+        });
+    }
+}
@@ -70,7 +70,7 @@ pub enum FromUintError<T> {
 
 /// Error for [`TryFrom<Uint>`][TryFrom] for [`ark_ff`](https://docs.rs/ark-ff) and others.
 #[allow(dead_code)] // This is used by some support features.
-#[derive(Debug, Clone, Copy, Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
 pub enum ToFieldError {
     /// Number is equal or larger than the target field modulus.
     #[error("Number is equal or larger than the target field modulus.")]
@@ -446,8 +446,9 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<f64> for Uint<BITS, LIMBS> {
         // All non-normal cases should have been handled above
         assert!(value.is_normal());
 
-        // Add offset to round to nearest integer.
-        let value = value + 0.5;
+        // Round to nearest integer. Adding `0.5` instead would round odd
+        // integers between 2^52 and 2^53 up.
+        let value = value.round();
 
         // Parse IEEE-754 double
         // Sign should be zero, exponent should be >= 0.
@@ -675,5 +676,9 @@ mod test {
             Uint::<7, 1>::try_from(123.500_f64),
             Ok(Uint::from_limbs([124]))
         );
+        assert_eq!(
+            Uint::<64, 1>::try_from(4_503_599_627_370_497.0_f64),
+            Ok(Uint::from(4_503_599_627_370_497_u64))
+        );
     }
 }
@@ -0,0 +1,309 @@
+use crate::Uint;
+use std::io::{Error as IoError, ErrorKind, Read, Result as IoResult, Write};
+use thiserror::Error;
+
+/// Error for [`decode_uleb128`][Uint::decode_uleb128] and
+/// [`read_uleb128`][Uint::read_uleb128].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum Leb128Error {
+    /// The value is too large to fit the target type.
+    ///
+    /// `.0` is `BITS`.
+    #[error("ULEB128 value is too large for Uint<{0}>")]
+    Overflow(usize),
+
+    /// The encoding has redundant trailing zero groups.
+    #[error("ULEB128 encoding is not canonical")]
+    NonCanonical,
+
+    /// The input ended before the final byte.
+    #[error("ULEB128 encoding is truncated")]
+    Truncated,
+}
+
+impl From<Leb128Error> for IoError {
+    fn from(error: Leb128Error) -> Self {
+        match error {
+            Leb128Error::Truncated => Self::new(ErrorKind::UnexpectedEof, error),
+            _ => Self::new(ErrorKind::InvalidData, error),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// The maximum length of a canonical ULEB128 encoding of this type.
+    const ULEB128_MAX_LEN: usize = if BITS == 0 { 1 } else { BITS.div_ceil(7) };
+
+    /// Returns the 7 bits starting at bit `index`.
+    #[allow(clippy::cast_possible_truncation)] // Masked to 7 bits
+    const fn septet(&self, index: usize) -> u8 {
+        let (limb, bit) = (index / 64, index % 64);
+        let mut result = self.limbs[limb] >> bit;
+        if bit > 64 - 7 && limb + 1 < LIMBS {
+            result |= self.limbs[limb + 1] << (64 - bit);
+        }
+        (result & 0x7f) as u8
+    }
+
+    /// Encodes the [`Uint`] as unsigned LEB128 into a new vector.
+    ///
+    /// The result is the canonical encoding, i.e. the shortest one. Zero is
+    /// encoded as a single zero byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// assert_eq!(0_U64.to_uleb128_vec(), [0x00]);
+    /// assert_eq!(624485_U64.to_uleb128_vec(), [0xe5, 0x8e, 0x26]);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_uleb128_vec(&self) -> Vec<u8> {
+        let len = self.bit_len().div_ceil(7);
+        if len == 0 {
+            return vec![0];
+        }
+        let mut result: Vec<u8> = (0..len).map(|i| self.septet(7 * i) | 0x80).collect();
+        result[len - 1] &= 0x7f;
+        result
+    }
+
+    /// Writes the [`Uint`] as unsigned LEB128 to `writer`.
+    ///
+    /// Returns the number of bytes written. See [`Self::to_uleb128_vec`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the `writer`.
+    pub fn write_uleb128<W: Write>(&self, writer: &mut W) -> IoResult<usize> {
+        let bytes = self.to_uleb128_vec();
+        writer.write_all(&bytes)?;
+        Ok(bytes.len())
+    }
+
+    /// Decodes an unsigned LEB128 value from the start of `bytes`.
+    ///
+    /// Returns the value and the number of bytes read. Any remaining bytes are
+    /// ignored.
+    ///
+    /// # Errors
+    ///
+    /// * [`Leb128Error::Overflow`] if the value does not fit the [`Uint`].
+    /// * [`Leb128Error::NonCanonical`] if the encoding has trailing zero
+    ///   groups, for example `[0x80, 0x00]` for zero.
+    /// * [`Leb128Error::Truncated`] if `bytes` ends before the final byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, Leb128Error, aliases::U64};
+    /// # uint!{
+    /// assert_eq!(
+    ///     U64::decode_uleb128(&[0xe5, 0x8e, 0x26, 0xff]),
+    ///     Ok((624485_U64, 3))
+    /// );
+    /// assert_eq!(
+    ///     U64::decode_uleb128(&[0xe5, 0x8e, 0xa6, 0x00]),
+    ///     Err(Leb128Error::NonCanonical)
+    /// );
+    /// # }
+    /// ```
+    pub fn decode_uleb128(bytes: &[u8]) -> Result<(Self, usize), Leb128Error> {
+        let mut decoder = Uleb128Decoder::<BITS, LIMBS>::default();
+        for (index, &byte) in bytes.iter().enumerate() {
+            if let Some(value) = decoder.push(byte)? {
+                return Ok((value, index + 1));
+            }
+        }
+        Err(Leb128Error::Truncated)
+    }
+
+    /// Reads an unsigned LEB128 value from `reader`.
+    ///
+    /// Reads exactly the bytes of the encoding, one at a time. Wrap the reader
+    /// in a [`BufReader`](std::io::BufReader) if this is slow.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ErrorKind::InvalidData`] error wrapping a [`Leb128Error`]
+    /// for invalid encodings (see [`Self::decode_uleb128`]) and any error from
+    /// the `reader`, including [`ErrorKind::UnexpectedEof`].
+    pub fn read_uleb128<R: Read>(reader: &mut R) -> IoResult<Self> {
+        let mut decoder = Uleb128Decoder::<BITS, LIMBS>::default();
+        let mut byte = [0];
+        loop {
+            reader.read_exact(&mut byte)?;
+            if let Some(value) = decoder.push(byte[0])? {
+                return Ok(value);
+            }
+        }
+    }
+}
+
+/// Incremental unsigned LEB128 decoder.
+struct Uleb128Decoder<const BITS: usize, const LIMBS: usize> {
+    limbs: [u64; LIMBS],
+    index: usize,
+}
+
+impl<const BITS: usize, const LIMBS: usize> Default for Uleb128Decoder<BITS, LIMBS> {
+    fn default() -> Self {
+        Self {
+            limbs: [0; LIMBS],
+            index: 0,
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uleb128Decoder<BITS, LIMBS> {
+    /// Adds the next byte, returns the value when complete.
+    fn push(&mut self, byte: u8) -> Result<Option<Uint<BITS, LIMBS>>, Leb128Error> {
+        let last = byte & 0x80 == 0;
+        let payload = u64::from(byte & 0x7f);
+        if last && payload == 0 && self.index > 0 {
+            return Err(Leb128Error::NonCanonical);
+        }
+        if self.index >= Uint::<BITS, LIMBS>::ULEB128_MAX_LEN {
+            // Any valid value has terminated by now, this is either padding
+            // or bits past `BITS`.
+            return Err(if payload == 0 {
+                Leb128Error::NonCanonical
+            } else {
+                Leb128Error::Overflow(BITS)
+            });
+        }
+        if payload != 0 {
+            let shift = 7 * self.index;
+            if BITS - shift < 7 && payload >> (BITS - shift) != 0 {
+                return Err(Leb128Error::Overflow(BITS));
+            }
+            let (limb, bit) = (shift / 64, shift % 64);
+            self.limbs[limb] |= payload << bit;
+            if bit > 64 - 7 {
+                let high = payload >> (64 - bit);
+                if high != 0 {
+                    self.limbs[limb + 1] |= high;
+                }
+            }
+        }
+        self.index += 1;
+        Ok(if last {
+            Some(Uint::from_limbs(self.limbs))
+        } else {
+            None
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases::{U0, U128, U256, U64, U8},
+        const_for, nlimbs,
+    };
+    use proptest::proptest;
+
+    #[test]
+    fn test_uleb128() {
+        // See <https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128>
+        assert_eq!(U0::ZERO.to_uleb128_vec(), [0x00]);
+        assert_eq!(U8::from(0x7f).to_uleb128_vec(), [0x7f]);
+        assert_eq!(U8::from(0x80).to_uleb128_vec(), [0x80, 0x01]);
+        assert_eq!(U64::from(624_485).to_uleb128_vec(), [0xe5, 0x8e, 0x26]);
+        assert_eq!(U64::MAX.to_uleb128_vec(), [
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01
+        ]);
+        assert_eq!(U256::MAX.to_uleb128_vec().len(), 37);
+    }
+
+    #[test]
+    fn test_decode_uleb128() {
+        assert_eq!(U0::decode_uleb128(&[0x00]), Ok((U0::ZERO, 1)));
+        assert_eq!(U0::decode_uleb128(&[0x01]), Err(Leb128Error::Overflow(0)));
+        assert_eq!(U8::decode_uleb128(&[0xff, 0x01]), Ok((U8::from(0xff), 2)));
+        assert_eq!(
+            U8::decode_uleb128(&[0x80, 0x02]),
+            Err(Leb128Error::Overflow(8))
+        );
+        assert_eq!(
+            U8::decode_uleb128(&[0x80, 0x80, 0x01]),
+            Err(Leb128Error::Overflow(8))
+        );
+        assert_eq!(U8::decode_uleb128(&[0x80]), Err(Leb128Error::Truncated));
+        assert_eq!(U8::decode_uleb128(&[]), Err(Leb128Error::Truncated));
+        assert_eq!(
+            U64::decode_uleb128(&[0x80, 0x00]),
+            Err(Leb128Error::NonCanonical)
+        );
+        assert_eq!(
+            U64::decode_uleb128(&[0xe5, 0x8e, 0xa6, 0x80, 0x00]),
+            Err(Leb128Error::NonCanonical)
+        );
+        assert_eq!(
+            U8::decode_uleb128(&[0x80, 0x80, 0x80, 0x00]),
+            Err(Leb128Error::NonCanonical)
+        );
+        // Long runs of padding are rejected early.
+        let mut padded = vec![0x80; 1_000_000];
+        padded.push(0x01);
+        assert_eq!(
+            U128::decode_uleb128(&padded),
+            Err(Leb128Error::NonCanonical)
+        );
+    }
+
+    #[test]
+    fn test_read_write_uleb128() {
+        let mut buffer = Vec::new();
+        assert_eq!(U64::from(624_485).write_uleb128(&mut buffer).unwrap(), 3);
+        assert_eq!(U64::from(1).write_uleb128(&mut buffer).unwrap(), 1);
+        let mut reader = &buffer[..];
+        assert_eq!(U64::read_uleb128(&mut reader).unwrap(), U64::from(624_485));
+        assert_eq!(U64::read_uleb128(&mut reader).unwrap(), U64::from(1));
+        assert_eq!(
+            U64::read_uleb128(&mut reader).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+        let mut reader = &[0x80, 0x02][..];
+        assert_eq!(
+            U8::read_uleb128(&mut reader).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn test_uleb128_native() {
+        proptest!(|(value: u64)| {
+            let mut expected = Vec::new();
+            let mut remaining = value;
+            loop {
+                #[allow(clippy::cast_possible_truncation)]
+                let byte = (remaining & 0x7f) as u8;
+                remaining >>= 7;
+                if remaining == 0 {
+                    expected.push(byte);
+                    break;
+                }
+                expected.push(byte | 0x80);
+            }
+            assert_eq!(U64::from(value).to_uleb128_vec(), expected);
+        });
+    }
+
+    #[test]
+    fn test_uleb128_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let encoded = value.to_uleb128_vec();
+                assert!(encoded.len() <= U::ULEB128_MAX_LEN);
+                assert_eq!(U::decode_uleb128(&encoded), Ok((value, encoded.len())));
+                assert_eq!(U::read_uleb128(&mut &encoded[..]).unwrap(), value);
+            });
+        });
+    }
+}
@@ -18,18 +18,22 @@
 // See <https://stackoverflow.com/questions/61417452/how-to-get-a-feature-requirement-tag-in-the-documentation-generated-by-cargo-do>
 #![cfg_attr(has_doc_cfg, feature(doc_cfg))]
 
+mod abi;
 mod add;
 pub mod algorithms;
 pub mod aliases;
+mod alphabet;
 mod base_convert;
 mod bit_arr;
 mod bits;
 mod bytes;
 mod cmp;
 mod const_for;
+mod der;
 mod div;
 mod from;
 mod gcd;
+mod leb128;
 mod log;
 mod modular;
 mod mul;
@@ -48,12 +52,48 @@ pub use uint_dyn::UintDyn;
 #[doc(inline)]
 pub use bit_arr::Bits;
 
+#[cfg(feature = "ark-ff")]
+#[doc(inline)]
+pub use support::ark_ff::ArkUint;
+
+#[cfg(feature = "ciborium")]
+#[doc(inline)]
+pub use support::ciborium as cbor;
+
+#[cfg(feature = "prost")]
+#[doc(inline)]
+pub use support::prost as proto;
+
+#[cfg(feature = "redis")]
+#[doc(inline)]
+pub use support::redis;
+
+#[cfg(feature = "sqlx")]
+#[doc(inline)]
+pub use support::sqlx;
+
+#[cfg(feature = "rusqlite")]
+#[doc(inline)]
+pub use support::rusqlite::FromSqliteError;
+
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+#[doc(inline)]
+pub use support::decimal::{DecimalError, DecimalValue, Rounding};
+
+#[cfg(feature = "rkyv")]
+#[doc(inline)]
+pub use support::rkyv::ArchivedUint;
+
 #[doc(inline)]
 pub use self::{
+    abi::AbiError,
+    alphabet::Alphabet,
     base_convert::BaseConvertError,
     bytes::nbytes,
+    der::DerError,
     from::{FromUintError, ToFieldError, ToUintError, UintTryFrom, UintTryTo},
-    string::ParseError,
+    leb128::Leb128Error,
+    string::{ParseError, ParseErrorKind, ParseOptions},
 };
 
 #[doc(inline)]
@@ -123,6 +163,7 @@ pub mod nightly {
 ///
 /// [std-overflow]: https://doc.rust-lang.org/reference/expressions/operator-expr.html#overflow
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(transparent)]
 pub struct Uint<const BITS: usize, const LIMBS: usize> {
     limbs: [u64; LIMBS],
 }
@@ -347,6 +388,7 @@ pub mod bench {
         log::bench::group(criterion);
         root::bench::group(criterion);
         modular::bench::group(criterion);
+        string::bench::group(criterion);
         algorithms::bench::group(criterion);
     }
 }
@@ -1,79 +1,78 @@
-use crate::{base_convert::BaseConvertError, utils::rem_up, Uint};
+use crate::{
+    algorithms::divrem_nby1,
+    base_convert::{limbs_to_chunks, limbs_to_digits_be, BaseConvertError, DC_THRESHOLD},
+    Uint,
+};
 use core::fmt::{
-    Binary, Debug, Display, Formatter, LowerHex, Octal, Result as FmtResult, UpperHex,
+    Alignment, Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result as FmtResult,
+    UpperExp, UpperHex, Write,
 };
 use std::str::FromStr;
-use thiserror::Error;
 
-// FEATURE: Respect width parameter in formatters.
+// The formatting traits write the digits in chunks that fit a `u64` directly
+// into the `Formatter`, and handle width, fill, alignment, sign and prefix
+// exactly like `Formatter::pad_integral` does for the primitive integers.
 
+/// Formats the value in decimal.
+///
+/// Up to 24 limbs (1536 bits) this does not allocate. Larger values are split
+/// by divide and conquer, which allocates temporary buffers but takes
+/// subquadratic time instead of quadratic. The same holds for [`Octal`],
+/// [`LowerExp`] and [`UpperExp`], while hexadecimal and binary formatting never
+/// allocate.
 impl<const BITS: usize, const LIMBS: usize> Display for Uint<BITS, LIMBS> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         // Base convert 19 digits at a time
         const BASE: u64 = 10_000_000_000_000_000_000_u64;
-        let mut spigot = self.to_base_be(BASE);
-        write!(f, "{}", spigot.next().unwrap_or(0))?;
-        for digits in spigot {
-            write!(f, "{:019}", digits)?;
-        }
-        Ok(())
+        let chunks = self.extract_chunks(BASE);
+        pad_chunks(f, "", chunks.iter(), 10, 19, |f, chunk, width| {
+            write!(f, "{chunk:0width$}")
+        })
     }
 }
 
 impl<const BITS: usize, const LIMBS: usize> Debug for Uint<BITS, LIMBS> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{:#x}_U{}", self, BITS)
+        write!(f, "{self:#x}_U{BITS}")
     }
 }
 
 impl<const BITS: usize, const LIMBS: usize> LowerHex for Uint<BITS, LIMBS> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        let mut limbs = self.as_limbs().iter().rev();
-        if let Some(first) = limbs.next() {
-            let width = 2 * rem_up(Self::BYTES, 8);
-            write!(f, "{:0width$x}", first, width = width)?;
-        }
-        for limb in limbs {
-            write!(f, "{:016x}", limb)?;
-        }
-        Ok(())
+        pad_chunks(
+            f,
+            "0x",
+            self.significant_limbs().iter().copied(),
+            16,
+            16,
+            |f, chunk, width| write!(f, "{chunk:0width$x}"),
+        )
     }
 }
 
 impl<const BITS: usize, const LIMBS: usize> UpperHex for Uint<BITS, LIMBS> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        let mut limbs = self.as_limbs().iter().rev();
-        if let Some(first) = limbs.next() {
-            let width = 2 * rem_up(Self::BYTES, 8);
-            write!(f, "{:0width$X}", first, width = width)?;
-        }
-        for limb in limbs {
-            write!(f, "{:016X}", limb)?;
-        }
-        Ok(())
+        pad_chunks(
+            f,
+            "0x",
+            self.significant_limbs().iter().copied(),
+            16,
+            16,
+            |f, chunk, width| write!(f, "{chunk:0width$X}"),
+        )
     }
 }
 
 impl<const BITS: usize, const LIMBS: usize> Binary for Uint<BITS, LIMBS> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        if f.alternate() {
-            write!(f, "0b")?;
-        }
-        let mut limbs = self.as_limbs().iter().rev();
-        if let Some(first) = limbs.next() {
-            let width = rem_up(Self::BITS, 64);
-            write!(f, "{:0width$b}", first, width = width)?;
-        }
-        for limb in limbs {
-            write!(f, "{:064b}", limb)?;
-        }
-        Ok(())
+        pad_chunks(
+            f,
+            "0b",
+            self.significant_limbs().iter().copied(),
+            2,
+            64,
+            |f, chunk, width| write!(f, "{chunk:0width$b}"),
+        )
     }
 }
 
@@ -81,29 +80,397 @@ impl<const BITS: usize, const LIMBS: usize> Octal for Uint<BITS, LIMBS> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         // Base convert 21 digits at a time
         const BASE: u64 = 0x8000_0000_0000_0000_u64;
-        let mut spigot = self.to_base_be(BASE);
-        write!(f, "{:o}", spigot.next().unwrap_or(0))?;
-        for digits in spigot {
-            write!(f, "{:021o}", digits)?;
+        let chunks = self.extract_chunks(BASE);
+        pad_chunks(f, "0o", chunks.iter(), 8, 21, |f, chunk, width| {
+            write!(f, "{chunk:0width$o}")
+        })
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// The limbs without the leading zero limbs, little-endian.
+    fn significant_limbs(&self) -> &[u64] {
+        let len = LIMBS
+            - self
+                .limbs
+                .iter()
+                .rev()
+                .take_while(|&&limb| limb == 0)
+                .count();
+        &self.limbs[..len]
+    }
+
+    /// The base `base` digits in little-endian order. Zero has no digits.
+    ///
+    /// Above [`DC_THRESHOLD`] limbs the digits are computed by divide and
+    /// conquer, which allocates but is faster, about four times at 2048 limbs.
+    fn extract_chunks(&self, base: u64) -> Chunks<LIMBS> {
+        let mut chunks = Chunks::new();
+        if LIMBS > DC_THRESHOLD {
+            for chunk in limbs_to_chunks(&self.limbs, base) {
+                chunks.push(chunk);
+            }
+            return chunks;
+        }
+        let mut limbs = self.limbs;
+        let mut len = self.significant_limbs().len();
+        while len > 0 {
+            chunks.push(divrem_nby1(&mut limbs[..len], base));
+            while len > 0 && limbs[len - 1] == 0 {
+                len -= 1;
+            }
+        }
+        chunks
+    }
+}
+
+/// Digit chunks from [`Uint::extract_chunks`], little-endian.
+///
+/// Two halves of `LIMBS` hold the digits for any base of at least `2^32`. They
+/// are separate arrays because `2 * LIMBS` can not be an array length.
+struct Chunks<const LIMBS: usize> {
+    halves: [[u64; LIMBS]; 2],
+    len:    usize,
+}
+
+impl<const LIMBS: usize> Chunks<LIMBS> {
+    const fn new() -> Self {
+        Self {
+            halves: [[0; LIMBS]; 2],
+            len:    0,
+        }
+    }
+
+    const fn push(&mut self, chunk: u64) {
+        self.halves[self.len / LIMBS][self.len % LIMBS] = chunk;
+        self.len += 1;
+    }
+
+    fn get(&self, index: usize) -> Option<u64> {
+        (index < self.len).then(|| self.halves[index / LIMBS][index % LIMBS])
+    }
+
+    fn iter(&self) -> impl DoubleEndedIterator<Item = u64> + ExactSizeIterator + '_ {
+        (0..self.len).map(|index| self.halves[index / LIMBS][index % LIMBS])
+    }
+}
+
+/// Writes little-endian `chunks` of `chunk_len` digits in `radix` the way
+/// `Formatter::pad_integral` does, without allocating.
+///
+/// `write_chunk` writes a chunk zero padded to a width. An empty `chunks`
+/// formats as zero.
+fn pad_chunks(
+    f: &mut Formatter<'_>,
+    prefix: &str,
+    mut chunks: impl DoubleEndedIterator<Item = u64> + ExactSizeIterator,
+    radix: u64,
+    chunk_len: usize,
+    write_chunk: fn(&mut Formatter<'_>, u64, usize) -> FmtResult,
+) -> FmtResult {
+    let top = chunks.next_back().unwrap_or(0);
+    let digits = top.checked_ilog(radix).map_or(1, |log| log as usize + 1);
+    pad_digits(f, prefix, digits + chunk_len * chunks.len(), |f| {
+        write_chunk(f, top, 0)?;
+        for chunk in chunks.rev() {
+            write_chunk(f, chunk, chunk_len)?;
         }
         Ok(())
+    })
+}
+
+/// Pads the `len` characters written by `write_digits` the way
+/// `Formatter::pad_integral` does for a non-negative number.
+fn pad_digits(
+    f: &mut Formatter<'_>,
+    prefix: &str,
+    len: usize,
+    write_digits: impl FnOnce(&mut Formatter<'_>) -> FmtResult,
+) -> FmtResult {
+    let sign = if f.sign_plus() { "+" } else { "" };
+    let prefix = if f.alternate() { prefix } else { "" };
+    let len = sign.len() + prefix.len() + len;
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    if f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+        f.write_str(prefix)?;
+        for _ in 0..padding {
+            f.write_char('0')?;
+        }
+        return write_digits(f);
+    }
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    f.write_str(prefix)?;
+    write_digits(f)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+impl<const BITS: usize, const LIMBS: usize> LowerExp for Uint<BITS, LIMBS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_exp(f, 'e')
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> UpperExp for Uint<BITS, LIMBS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_exp(f, 'E')
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Formats in scientific notation like the primitive integers do.
+    ///
+    /// Without precision all significant digits are written. With precision
+    /// the mantissa is rounded half to even, or padded with zeros.
+    fn fmt_exp(&self, f: &mut Formatter<'_>, marker: char) -> FmtResult {
+        const BASE: u64 = 10_000_000_000_000_000_000_u64;
+        let chunks = self.extract_chunks(BASE);
+        let top = chunks.iter().next_back().unwrap_or(0);
+        let len = top.checked_ilog10().map_or(1, |log| log as usize + 1)
+            + 19 * chunks.len.saturating_sub(1);
+
+        // The decimal digit `index` places after the most significant one.
+        #[allow(clippy::cast_possible_truncation)] // Less than 19
+        let digit = |index: usize| {
+            let place = len - 1 - index;
+            let chunk = chunks.get(place / 19).unwrap_or(0);
+            chunk / 10_u64.pow((place % 19) as u32) % 10
+        };
+        let significant = (1..len)
+            .rev()
+            .find(|&index| digit(index) != 0)
+            .map_or(1, |index| index + 1);
+
+        // When rounding up, the digit at `carry` is incremented and the ones
+        // after it become zero. If all digits are nines, `carry` is `None`
+        // and the mantissa becomes a one followed by zeros.
+        let mut exponent = len - 1;
+        let mut mantissa = significant;
+        let mut round_up = None;
+        if let Some(precision) = f.precision() {
+            mantissa = precision + 1;
+            if significant > mantissa {
+                // The remaining digits are non-zero after the first, since
+                // `significant` excludes trailing zeros.
+                let round = digit(mantissa);
+                let odd = digit(mantissa - 1) % 2 == 1;
+                if round > 5 || (round == 5 && (significant > mantissa + 1 || odd)) {
+                    let carry = (0..mantissa).rev().find(|&index| digit(index) != 9);
+                    if carry.is_none() {
+                        exponent += 1;
+                    }
+                    round_up = Some(carry);
+                }
+            }
+        }
+        let mantissa_digit = |index: usize| match round_up {
+            Some(Some(carry)) if index == carry => digit(index) + 1,
+            Some(Some(carry)) if index > carry => 0,
+            Some(None) => u64::from(index == 0),
+            _ if index < len => digit(index),
+            _ => 0,
+        };
+
+        let exponent_len = exponent.checked_ilog10().map_or(1, |log| log as usize + 1);
+        let chars = mantissa + usize::from(mantissa > 1) + 1 + exponent_len;
+        pad_digits(f, "", chars, |f| {
+            write!(f, "{}", mantissa_digit(0))?;
+            if mantissa > 1 {
+                f.write_char('.')?;
+                for index in 1..mantissa {
+                    write!(f, "{}", mantissa_digit(index))?;
+                }
+            }
+            write!(f, "{marker}{exponent}")
+        })
     }
 }
 
-/// Error for [`from_str_radix`](Uint::from_str_radix).
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Error)]
-pub enum ParseError {
-    /// Invalid digit in string.
-    #[error("invalid digit")]
+/// Error for [`from_str_radix`](Uint::from_str_radix) and the other parsing
+/// functions.
+///
+/// It has the [`kind`](Self::kind) of error, the byte [`offset`](Self::offset)
+/// in the input where it was found and the [`radix`](Self::radix) in effect.
+/// For errors about the value as a whole, like overflow, the offset is the
+/// start of its digits. Errors converted from [`BaseConvertError`] have no
+/// input text, so they have no offset and may have no radix.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::{aliases::U64, ParseErrorKind};
+/// let error = "0x12g4".parse::<U64>().unwrap_err();
+/// assert_eq!(error.kind(), ParseErrorKind::InvalidDigit('g'));
+/// assert_eq!(error.offset(), Some(4));
+/// assert_eq!(error.radix(), Some(16));
+/// assert_eq!(
+///     error.to_string(),
+///     "invalid digit 'g' for radix 16 at byte 4"
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    kind:   ParseErrorKind,
+    offset: Option<usize>,
+    radix:  Option<u64>,
+}
+
+/// The kind of [`ParseError`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input has no digits.
+    Empty,
+
+    /// The character is not a digit in the radix.
     InvalidDigit(char),
 
-    /// Invalid radix, up to base 64 is supported.
-    #[error("invalid radix, up to 64 is supported")]
-    InvalidRadix(u64),
+    /// The digit `.0` is not less than the radix.
+    ///
+    /// This comes from [`BaseConvertError::InvalidDigit`], where digits are
+    /// numbers instead of characters.
+    DigitOutOfRange(u64),
 
-    /// Error from [`Uint::from_base_be`].
-    #[error(transparent)]
-    BaseConvertError(#[from] BaseConvertError),
+    /// The radix is not in the supported range of 2 to 64.
+    InvalidRadix,
+
+    /// The input starts with a radix prefix that is unknown or not allowed.
+    BadPrefix,
+
+    /// The value is too large for the [`Uint`].
+    Overflow,
+
+    /// The input has more digits than the largest value, not counting
+    /// leading zeros.
+    ///
+    /// `.0` is the maximum number of digits. This is detected before any
+    /// conversion so that long untrusted inputs are rejected cheaply.
+    InputTooLong(usize),
+
+    /// The number in scientific notation has a fractional part.
+    NotAnInteger,
+
+    /// The checksum does not match, see [`Uint::from_base58check`].
+    InvalidChecksum,
+}
+
+impl ParseError {
+    /// Creates an error of `kind` at byte `offset` of an input in `radix`.
+    #[must_use]
+    pub const fn new(kind: ParseErrorKind, offset: usize, radix: u64) -> Self {
+        Self {
+            kind,
+            offset: Some(offset),
+            radix: Some(radix),
+        }
+    }
+
+    /// The kind of error.
+    #[must_use]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the input where the error was found, if there was
+    /// an input text.
+    #[must_use]
+    pub const fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// The radix in effect, if it is known.
+    #[must_use]
+    pub const fn radix(&self) -> Option<u64> {
+        self.radix
+    }
+
+    /// Moves the offset by `start`, for errors in a part of a larger input.
+    fn shifted(mut self, start: usize) -> Self {
+        self.offset = self.offset.map(|offset| offset + start);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let Self {
+            kind,
+            offset,
+            radix,
+        } = *self;
+        let at = Known(" at byte ", offset);
+        match kind {
+            ParseErrorKind::Empty => write!(f, "no digits{at}"),
+            ParseErrorKind::InvalidDigit(c) => {
+                write!(f, "invalid digit {c:?}{}{at}", Known(" for radix ", radix))
+            }
+            ParseErrorKind::DigitOutOfRange(digit) => {
+                write!(
+                    f,
+                    "digit {digit} is out of range{}",
+                    Known(" for radix ", radix)
+                )
+            }
+            ParseErrorKind::InvalidRadix => {
+                write!(
+                    f,
+                    "invalid radix{}, 2 to 64 are supported",
+                    Known(" ", radix)
+                )
+            }
+            ParseErrorKind::BadPrefix => write!(f, "invalid radix prefix{at}"),
+            ParseErrorKind::Overflow => write!(f, "value{at} is too large"),
+            ParseErrorKind::InputTooLong(max) => {
+                write!(f, "value{at} is too long, at most {max} digits are allowed")
+            }
+            ParseErrorKind::NotAnInteger => write!(f, "value{at} is not an integer"),
+            ParseErrorKind::InvalidChecksum => write!(f, "invalid checksum"),
+        }
+    }
+}
+
+/// Displays the label followed by the value, or nothing if the value is not
+/// known.
+struct Known<T>(&'static str, Option<T>);
+
+impl<T: Display> Display for Known<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let Self(label, value) = self;
+        value
+            .as_ref()
+            .map_or(Ok(()), |value| write!(f, "{label}{value}"))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<BaseConvertError> for ParseError {
+    fn from(error: BaseConvertError) -> Self {
+        let (kind, radix) = match error {
+            BaseConvertError::Overflow => (ParseErrorKind::Overflow, None),
+            BaseConvertError::InvalidBase(base) => (ParseErrorKind::InvalidRadix, Some(base)),
+            BaseConvertError::InvalidDigit(digit, base) => {
+                (ParseErrorKind::DigitOutOfRange(digit), Some(base))
+            }
+        };
+        Self {
+            kind,
+            offset: None,
+            radix,
+        }
+    }
 }
 
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
@@ -114,19 +481,30 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// 0—9, {+-}, {/,_} is used. That is, for base 64 it is compatible with
     /// all the common base64 variants.
     ///
+    /// Leading zeros are skipped, after which the input can have at most as
+    /// many digits as [`Uint::MAX`] in this radix.
+    ///
     /// # Errors
     ///
-    /// * [`ParseError::InvalidDigit`] if the string contains a non-digit.
-    /// * [`ParseError::InvalidRadix`] if the radix is larger than 64.
-    /// * [`ParseError::BaseConvertError`] if [`Uint::from_base_be`] fails.
+    /// Returns a [`ParseError`] with the byte offset and one of the kinds:
+    ///
+    /// * [`ParseErrorKind::InvalidRadix`] if the radix is not in 2 to 64.
+    /// * [`ParseErrorKind::Empty`] if there are no digits.
+    /// * [`ParseErrorKind::InvalidDigit`] if the string contains a non-digit.
+    /// * [`ParseErrorKind::InputTooLong`] if there are too many digits.
+    /// * [`ParseErrorKind::Overflow`] if the value is too large.
     // FEATURE: Support proper unicode. Ignore zero-width spaces, joiners, etc.
     // Recognize digits from other alphabets.
     pub fn from_str_radix(src: &str, radix: u64) -> Result<Self, ParseError> {
-        if radix > 64 {
-            return Err(ParseError::InvalidRadix(radix));
+        let error = |kind, offset| ParseError::new(kind, offset, radix);
+        if !(2..=64).contains(&radix) {
+            return Err(error(ParseErrorKind::InvalidRadix, 0));
         }
+        let max = max_digits(BITS, radix);
+        let mut empty = true;
+        let mut count = 0;
         let mut err = None;
-        let digits = src.chars().filter_map(|c| {
+        let digits = src.char_indices().filter_map(|(offset, c)| {
             if err.is_some() {
                 return None;
             }
@@ -137,52 +515,370 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
                     'a'..='z' => u64::from(c) - u64::from('a') + 10,
                     'A'..='Z' => u64::from(c) - u64::from('A') + 10,
                     '_' => return None, // Ignored character.
-                    _ => {
-                        err = Some(ParseError::InvalidDigit(c));
-                        return None;
-                    }
+                    _ => u64::MAX,
                 }
             } else {
                 // The Base-64 alphabets
                 match c {
                     'A'..='Z' => u64::from(c) - u64::from('A'),
-                    'a'..='f' => u64::from(c) - u64::from('a') + 26,
+                    'a'..='z' => u64::from(c) - u64::from('a') + 26,
                     '0'..='9' => u64::from(c) - u64::from('0') + 52,
                     '+' | '-' => 62,
                     '/' | ',' | '_' => 63,
                     '=' | '\r' | '\n' => return None, // Ignored characters.
-                    _ => {
-                        err = Some(ParseError::InvalidDigit(c));
-                        return None;
-                    }
+                    _ => u64::MAX,
                 }
             };
+            if digit >= radix {
+                err = Some(error(ParseErrorKind::InvalidDigit(c), offset));
+                return None;
+            }
+            empty = false;
+            // Leading zeros do not change the value.
+            if count == 0 && digit == 0 {
+                return None;
+            }
+            count += 1;
+            if count > max {
+                err = Some(error(ParseErrorKind::InputTooLong(max), 0));
+                return None;
+            }
             Some(digit)
         });
-        let value = Self::from_base_be(radix, digits)?;
-        err.map_or(Ok(value), Err)
+        let result = Self::from_base_be(radix, digits);
+        if let Some(err) = err {
+            return Err(err);
+        }
+        if empty {
+            return Err(error(ParseErrorKind::Empty, 0));
+        }
+        result.map_err(|_| error(ParseErrorKind::Overflow, 0))
+    }
+
+    /// Formats the [`Uint`] in the given radix.
+    ///
+    /// This is the inverse of [`Uint::from_str_radix`]. For bases 2 to 36 the
+    /// digits are 0—9, a—z in lower case. For bases 37 to 64 it is the
+    /// standard base64 alphabet A—Z, a—z, 0—9, `+`, `/`. There is no prefix
+    /// and no padding, zero is the single digit for zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is less than 2 or larger than 64.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// assert_eq!(255_U64.to_string_radix(2), "11111111");
+    /// assert_eq!(1295_U64.to_string_radix(36), "zz");
+    /// assert_eq!(0_U64.to_string_radix(36), "0");
+    /// assert_eq!(4095_U64.to_string_radix(64), "//");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_string_radix(&self, radix: u64) -> String {
+        const LOWER: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+        const BASE64: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        assert!((2..=64).contains(&radix), "radix must be in 2..=64");
+        let alphabet = if radix <= 36 { LOWER } else { BASE64 };
+        let digits = limbs_to_digits_be(self.limbs, radix);
+        if digits.is_empty() {
+            return char::from(alphabet[0]).to_string();
+        }
+        #[allow(clippy::cast_possible_truncation)] // Digits are less than radix
+        digits
+            .into_iter()
+            .map(|digit| char::from(alphabet[digit as usize]))
+            .collect()
+    }
+}
+
+/// Options for [`Uint::from_str_with_options`].
+///
+/// The defaults are those of [`FromStr`]: the radix is detected from a `0x`,
+/// `0o` or `0b` prefix and is ten otherwise, `_` separators and scientific
+/// notation are allowed and surrounding whitespace is not.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::{aliases::U64, ParseOptions};
+/// let options = ParseOptions::new().allow_whitespace(true);
+/// assert_eq!(options.parse(" 2.5e6\n"), Ok(U64::from(2_500_000)));
+///
+/// let options = ParseOptions::new().radix(16).allow_separators(false);
+/// assert_eq!(options.parse("ff"), Ok(U64::from(255)));
+/// assert!(options.parse::<64, 1>("f_f").is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    radix:      Option<u64>,
+    whitespace: bool,
+    separators: bool,
+    exponent:   bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseOptions {
+    /// The default options, the same as used by [`FromStr`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            radix:      None,
+            whitespace: false,
+            separators: true,
+            exponent:   true,
+        }
+    }
+
+    /// Parses in the given radix instead of detecting it from the prefix.
+    ///
+    /// A `0x`, `0o` or `0b` prefix is then not allowed.
+    #[must_use]
+    pub const fn radix(mut self, radix: u64) -> Self {
+        self.radix = Some(radix);
+        self
+    }
+
+    /// Whether to ignore leading and trailing whitespace.
+    #[must_use]
+    pub const fn allow_whitespace(mut self, allow: bool) -> Self {
+        self.whitespace = allow;
+        self
+    }
+
+    /// Whether to ignore `_` digit separators, for radices up to 36.
+    #[must_use]
+    pub const fn allow_separators(mut self, allow: bool) -> Self {
+        self.separators = allow;
+        self
+    }
+
+    /// Whether to allow scientific notation like `2.5e6` in radix ten.
+    ///
+    /// The value must be an exact integer, so `1.5e1` is accepted and
+    /// `1.55e1` is not.
+    #[must_use]
+    pub const fn allow_exponent(mut self, allow: bool) -> Self {
+        self.exponent = allow;
+        self
+    }
+
+    /// Parses a string into a [`Uint`] with these options.
+    ///
+    /// # Errors
+    ///
+    /// See [`Uint::from_str_with_options`].
+    pub fn parse<const BITS: usize, const LIMBS: usize>(
+        &self,
+        src: &str,
+    ) -> Result<Uint<BITS, LIMBS>, ParseError> {
+        Uint::from_str_with_options(src, self)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Parses a string into a [`Uint`] with the given [`ParseOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{aliases::U256, ParseErrorKind, ParseOptions};
+    /// let options = ParseOptions::new();
+    /// assert_eq!(
+    ///     U256::from_str_with_options("1e18", &options),
+    ///     Ok(U256::from(10_u64.pow(18)))
+    /// );
+    /// assert_eq!(
+    ///     U256::from_str_with_options("1_000_000", &options),
+    ///     Ok(U256::from(1_000_000))
+    /// );
+    /// let error = U256::from_str_with_options("1.55e1", &options).unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::NotAnInteger);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The errors of [`Uint::from_str_radix`], with offsets in `src`, and:
+    ///
+    /// * [`ParseErrorKind::BadPrefix`] if the radix is given and the input
+    ///   starts with a prefix for another radix.
+    /// * [`ParseErrorKind::InvalidDigit`] for disallowed separators and
+    ///   whitespace.
+    /// * [`ParseErrorKind::NotAnInteger`] if scientific notation has a
+    ///   fractional part.
+    pub fn from_str_with_options(src: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        let (start, src) = if options.whitespace {
+            let trimmed = src.trim_start();
+            (src.len() - trimmed.len(), trimmed.trim_end())
+        } else {
+            (0, src)
+        };
+        let (prefix, radix) = match options.radix {
+            Some(radix) if bad_prefix(src, radix) => {
+                return Err(ParseError::new(ParseErrorKind::BadPrefix, start, radix));
+            }
+            Some(radix) => (0, radix),
+            None => radix_prefix(src),
+        };
+        let start = start + prefix;
+        let src = &src[prefix..];
+        if radix <= 36 && !options.separators {
+            if let Some(offset) = src.find('_') {
+                let kind = ParseErrorKind::InvalidDigit('_');
+                return Err(ParseError::new(kind, start + offset, radix));
+            }
+        }
+        let result = if radix == 10 && options.exponent && src.contains(['.', 'e', 'E']) {
+            Self::from_str_scientific(src)
+        } else {
+            Self::from_str_radix(src, radix)
+        };
+        result.map_err(|err| err.shifted(start))
+    }
+
+    /// Parses a decimal number of the form `123.456e789` that has an integer
+    /// value. Digits can be separated by `_`.
+    fn from_str_scientific(src: &str) -> Result<Self, ParseError> {
+        let error = |kind, offset| ParseError::new(kind, offset, 10);
+        let (mantissa, exponent) = src.find(['e', 'E']).map_or((src, None), |index| {
+            (&src[..index], Some((index + 1, &src[index + 1..])))
+        });
+
+        // Significant digits without leading zeros and the decimal shift,
+        // which is wide enough to never overflow.
+        let mut digits = String::new();
+        let mut shift = 0_i128;
+        let mut empty = true;
+        let mut point = false;
+        for (offset, c) in mantissa.char_indices() {
+            match c {
+                '0'..='9' => {
+                    empty = false;
+                    shift -= i128::from(point);
+                    if !digits.is_empty() || c != '0' {
+                        digits.push(c);
+                    }
+                }
+                '_' => {}
+                '.' if !point => point = true,
+                _ => return Err(error(ParseErrorKind::InvalidDigit(c), offset)),
+            }
+        }
+        if empty {
+            return Err(error(ParseErrorKind::Empty, 0));
+        }
+        if let Some((start, exponent)) = exponent {
+            let (negative, start, exponent) = match exponent.as_bytes().first() {
+                Some(b'-') => (true, start + 1, &exponent[1..]),
+                Some(b'+') => (false, start + 1, &exponent[1..]),
+                _ => (false, start, exponent),
+            };
+            let mut value = None;
+            for (offset, c) in exponent.char_indices() {
+                match c {
+                    '0'..='9' => {
+                        let digit = i128::from(u8::try_from(c).unwrap_or_default() - b'0');
+                        let old = value.unwrap_or(0_i128);
+                        value = Some(old.saturating_mul(10).saturating_add(digit));
+                    }
+                    '_' => {}
+                    _ => return Err(error(ParseErrorKind::InvalidDigit(c), start + offset)),
+                }
+            }
+            let value = value.ok_or_else(|| error(ParseErrorKind::Empty, start))?;
+            shift += if negative { -value } else { value };
+        }
+
+        // Trailing zeros of the digits move into the shift.
+        let significant = digits.trim_end_matches('0');
+        if significant.is_empty() {
+            return Ok(Self::ZERO);
+        }
+        shift += i128::try_from(digits.len() - significant.len()).unwrap_or(i128::MAX);
+        if shift < 0 {
+            return Err(error(ParseErrorKind::NotAnInteger, 0));
+        }
+        // Errors are about the value as a whole, so they are at the start.
+        let value = Self::from_str_radix(significant, 10).map_err(|err| error(err.kind(), 0))?;
+        if shift == 0 {
+            return Ok(value);
+        }
+        usize::try_from(shift)
+            .ok()
+            .and_then(|shift| Self::try_from(10_u64).ok()?.checked_pow(shift))
+            .and_then(|scale| value.checked_mul(scale))
+            .ok_or_else(|| error(ParseErrorKind::Overflow, 0))
+    }
+}
+
+/// Returns the number of base `radix` digits of the largest `bits` bit number.
+///
+/// Inputs with more digits, not counting leading zeros, can never fit.
+///
+/// `ruint-macro` has a copy for literals, keep the two in sync.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+pub fn max_digits(bits: usize, radix: u64) -> usize {
+    debug_assert!(radix >= 2);
+    if bits == 0 {
+        return 0;
+    }
+    if radix.is_power_of_two() {
+        return bits.div_ceil(radix.trailing_zeros() as usize);
+    }
+    // The quotient is irrational for `bits > 0`, rounding up is exact unless
+    // it is extremely close to an integer. Then err on the long side.
+    let digits = bits as f64 / (radix as f64).log2();
+    if (digits - digits.round()).abs() < 1e-9 {
+        digits.round() as usize + 1
+    } else {
+        digits.ceil() as usize
     }
 }
 
+/// Returns the length of a `0x`, `0o` or `0b` prefix and the radix it
+/// denotes, or zero and ten without prefix.
+fn radix_prefix(src: &str) -> (usize, u64) {
+    match src.get(..2) {
+        Some("0x" | "0X") => (2, 16),
+        Some("0o" | "0O") => (2, 8),
+        Some("0b" | "0B") => (2, 2),
+        _ => (0, 10),
+    }
+}
+
+/// Whether `src` starts with a `0x`, `0o` or `0b` prefix whose letter is not a
+/// digit in `radix`.
+fn bad_prefix(src: &str, radix: u64) -> bool {
+    let digit = match src.get(..2) {
+        Some("0x" | "0X") => 33,
+        Some("0o" | "0O") => 24,
+        Some("0b" | "0B") => 11,
+        _ => return false,
+    };
+    radix <= 36 && digit >= radix
+}
+
 impl<const BITS: usize, const LIMBS: usize> FromStr for Uint<BITS, LIMBS> {
     type Err = ParseError;
 
     fn from_str(src: &str) -> Result<Self, Self::Err> {
-        if src.len() >= 2 {
-            match &src[..2] {
-                "0x" | "0X" => return Self::from_str_radix(&src[2..], 16),
-                "0o" | "0O" => return Self::from_str_radix(&src[2..], 8),
-                "0b" | "0B" => return Self::from_str_radix(&src[2..], 2),
-                _ => {}
-            }
-        }
-        Self::from_str_radix(src, 10)
+        Self::from_str_with_options(src, &ParseOptions::new())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{const_for, nlimbs, Alphabet};
     use proptest::proptest;
 
     #[allow(clippy::unreadable_literal)]
@@ -193,6 +889,15 @@ mod tests {
         0xc85ef7d79691fe79_u64,
     ]);
 
+    #[test]
+    fn test_from_str_radix_base64() {
+        // All of `a` to `z` are digits in the base-64 alphabets, not only `a` to `f`.
+        type U = Uint<64, 1>;
+        assert_eq!(U::from_str_radix("g", 64).unwrap(), U::from(32));
+        assert_eq!(U::from_str_radix("z", 64).unwrap(), U::from(51));
+        assert_eq!(U::from_str_radix("Bz", 64).unwrap(), U::from(64 + 51));
+    }
+
     #[test]
     fn test_num() {
         assert_eq!(
@@ -217,12 +922,390 @@ mod tests {
     fn test_hex() {
         proptest!(|(value: u64)| {
             let n: Uint<64, 1> = Uint::from(value);
-            assert_eq!(format!("{:x}", n), format!("{:016x}", value));
-            assert_eq!(format!("{:#x}", n), format!("{:#018x}", value));
-            assert_eq!(format!("{:X}", n), format!("{:016X}", value));
-            assert_eq!(format!("{:#X}", n), format!("{:#018X}", value));
-            assert_eq!(format!("{:b}", n), format!("{:064b}", value));
-            assert_eq!(format!("{:#b}", n), format!("{:#066b}", value));
+            assert_eq!(format!("{n:x}"), format!("{value:x}"));
+            assert_eq!(format!("{n:#x}"), format!("{value:#x}"));
+            assert_eq!(format!("{n:X}"), format!("{value:X}"));
+            assert_eq!(format!("{n:#X}"), format!("{value:#X}"));
+            assert_eq!(format!("{n:b}"), format!("{value:b}"));
+            assert_eq!(format!("{n:#b}"), format!("{value:#b}"));
+        });
+    }
+
+    #[test]
+    fn test_format_options() {
+        proptest!(|(value: u128)| {
+            let n: Uint<128, 2> = Uint::from(value);
+            assert_eq!(format!("{n:50}"), format!("{value:50}"));
+            assert_eq!(format!("{n:<50}"), format!("{value:<50}"));
+            assert_eq!(format!("{n:^50}"), format!("{value:^50}"));
+            assert_eq!(format!("{n:*^50}"), format!("{value:*^50}"));
+            assert_eq!(format!("{n:+}"), format!("{value:+}"));
+            assert_eq!(format!("{n:+050}"), format!("{value:+050}"));
+            assert_eq!(format!("{n:3}"), format!("{value:3}"));
+            assert_eq!(format!("{n:050x}"), format!("{value:050x}"));
+            assert_eq!(format!("{n:#050x}"), format!("{value:#050x}"));
+            assert_eq!(format!("{n:_<#50X}"), format!("{value:_<#50X}"));
+            assert_eq!(format!("{n:+#x}"), format!("{value:+#x}"));
+            assert_eq!(format!("{n:#3x}"), format!("{value:#3x}"));
+            assert_eq!(format!("{n:0140b}"), format!("{value:0140b}"));
+            assert_eq!(format!("{n:#0140b}"), format!("{value:#0140b}"));
+            assert_eq!(format!("{n:>#140b}"), format!("{value:>#140b}"));
+            assert_eq!(format!("{n:o}"), format!("{value:o}"));
+            assert_eq!(format!("{n:#o}"), format!("{value:#o}"));
+            assert_eq!(format!("{n:#060o}"), format!("{value:#060o}"));
+            assert_eq!(format!("{n:e}"), format!("{value:e}"));
+            assert_eq!(format!("{n:E}"), format!("{value:E}"));
+            assert_eq!(format!("{n:.0e}"), format!("{value:.0e}"));
+            assert_eq!(format!("{n:.3E}"), format!("{value:.3E}"));
+            assert_eq!(format!("{n:.40e}"), format!("{value:.40e}"));
+            assert_eq!(format!("{n:+.2e}"), format!("{value:+.2e}"));
+            assert_eq!(format!("{n:030.3e}"), format!("{value:030.3e}"));
+            assert_eq!(format!("{n:*^+30.5E}"), format!("{value:*^+30.5E}"));
+            assert_eq!(format!("{n:>50e}"), format!("{value:>50e}"));
+        });
+        proptest!(|(value: u128, precision in 0_usize..45)| {
+            let n: Uint<128, 2> = Uint::from(value);
+            assert_eq!(format!("{n:.precision$e}"), format!("{value:.precision$e}"));
+        });
+    }
+
+    #[test]
+    fn test_chunks() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                assert_eq!(value.to_string(), value.to_string_radix(10));
+                assert_eq!(format!("{value:o}"), value.to_string_radix(8));
+                assert_eq!(format!("{value:x}"), value.to_string_radix(16));
+                assert_eq!(format!("{value:b}"), value.to_string_radix(2));
+                let padded = format!("{value:*^1500}");
+                assert_eq!(padded.len(), 1500);
+                assert_eq!(padded.trim_matches('*'), value.to_string());
+                let padded = format!("{value:+#01500o}");
+                assert_eq!(padded.len(), 1500);
+                let octal = value.to_string_radix(8);
+                assert_eq!(&padded[..3], "+0o");
+                assert_eq!(padded[3..].trim_start_matches('0'), octal.trim_start_matches('0'));
+            });
+        });
+    }
+
+    #[test]
+    fn test_exp() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            assert_eq!(format!("{:e}", U::ZERO), "0e0");
+            assert_eq!(format!("{:.2E}", U::ZERO), "0.00E0");
+        });
+        assert_eq!(format!("{:e}", Uint::<64, 1>::from(1_200_u64)), "1.2e3");
+        assert_eq!(format!("{:.1e}", Uint::<64, 1>::from(1_250_u64)), "1.2e3");
+        assert_eq!(format!("{:.1e}", Uint::<64, 1>::from(1_350_u64)), "1.4e3");
+        assert_eq!(format!("{:.1e}", Uint::<64, 1>::from(1_251_u64)), "1.3e3");
+        assert_eq!(format!("{:.1e}", Uint::<64, 1>::from(9_960_u64)), "1.0e4");
+        assert_eq!(format!("{:.2e}", Uint::<256, 4>::MAX), "1.16e77");
+        let nines = Uint::<128, 2>::from(10_u128.pow(20) - 1);
+        assert_eq!(format!("{nines:.2e}"), "1.00e20");
+        assert_eq!(format!("{nines:e}"), "9.9999999999999999999e19");
+    }
+
+    #[test]
+    fn test_from_str() {
+        type U = Uint<64, 1>;
+        let parse = |src: &str| src.parse::<U>();
+        assert_eq!(parse("1_000_000"), Ok(U::from(1_000_000)));
+        assert_eq!(parse("0x_ff"), Ok(U::from(255)));
+        assert_eq!(parse("0b1010"), Ok(U::from(10)));
+        assert_eq!(parse("1e18"), Ok(U::from(10_u64.pow(18))));
+        assert_eq!(parse("2.5e6"), Ok(U::from(2_500_000)));
+        assert_eq!(parse("2.5E+6"), Ok(U::from(2_500_000)));
+        assert_eq!(parse("1_000.5e1"), Ok(U::from(10_005)));
+        assert_eq!(parse("1.5e1"), Ok(U::from(15)));
+        assert_eq!(parse("1.0"), Ok(U::from(1)));
+        assert_eq!(parse("1500e-2"), Ok(U::from(15)));
+        assert_eq!(parse(".5e1"), Ok(U::from(5)));
+        assert_eq!(parse("0e99999999999999999999999"), Ok(U::ZERO));
+        let error = |src: &str| parse(src).map_err(|e| (e.kind(), e.offset().unwrap()));
+        assert_eq!(error("1.55e1"), Err((ParseErrorKind::NotAnInteger, 0)));
+        assert_eq!(error("1.5"), Err((ParseErrorKind::NotAnInteger, 0)));
+        assert_eq!(error("15e-1"), Err((ParseErrorKind::NotAnInteger, 0)));
+        assert_eq!(error("e5"), Err((ParseErrorKind::Empty, 0)));
+        assert_eq!(error("1e"), Err((ParseErrorKind::Empty, 2)));
+        assert_eq!(error("1e-"), Err((ParseErrorKind::Empty, 3)));
+        assert_eq!(error("1.2.3"), Err((ParseErrorKind::InvalidDigit('.'), 3)));
+        assert_eq!(error("1e1.5"), Err((ParseErrorKind::InvalidDigit('.'), 3)));
+        assert_eq!(error("1e+1x"), Err((ParseErrorKind::InvalidDigit('x'), 4)));
+        assert_eq!(error(" 1"), Err((ParseErrorKind::InvalidDigit(' '), 0)));
+        assert_eq!(error("12a4"), Err((ParseErrorKind::InvalidDigit('a'), 2)));
+        assert_eq!(error("0x12g4"), Err((ParseErrorKind::InvalidDigit('g'), 4)));
+        assert_eq!(error(""), Err((ParseErrorKind::Empty, 0)));
+        assert_eq!(error("0x"), Err((ParseErrorKind::Empty, 2)));
+        assert_eq!(error("1e20"), Err((ParseErrorKind::Overflow, 0)));
+        assert_eq!(
+            error("0x1_0000_0000_0000_0000"),
+            Err((ParseErrorKind::InputTooLong(16), 2))
+        );
+        assert_eq!(
+            error("1e99999999999999999999999"),
+            Err((ParseErrorKind::Overflow, 0))
+        );
+        assert_eq!(
+            "1e0".parse::<Uint<0, 0>>().map_err(|e| e.kind()),
+            Err(ParseErrorKind::InputTooLong(0))
+        );
+        assert_eq!("1e0".parse::<Uint<1, 1>>(), Ok(Uint::from(1)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = |kind, offset, radix| ParseError::new(kind, offset, radix).to_string();
+        assert_eq!(
+            error(ParseErrorKind::InvalidDigit('g'), 4, 16),
+            "invalid digit 'g' for radix 16 at byte 4"
+        );
+        assert_eq!(error(ParseErrorKind::Empty, 2, 16), "no digits at byte 2");
+        assert_eq!(
+            error(ParseErrorKind::InvalidRadix, 0, 65),
+            "invalid radix 65, 2 to 64 are supported"
+        );
+        assert_eq!(
+            error(ParseErrorKind::BadPrefix, 1, 10),
+            "invalid radix prefix at byte 1"
+        );
+        assert_eq!(
+            error(ParseErrorKind::Overflow, 2, 16),
+            "value at byte 2 is too large"
+        );
+        assert_eq!(
+            error(ParseErrorKind::InputTooLong(20), 0, 10),
+            "value at byte 0 is too long, at most 20 digits are allowed"
+        );
+        let error = ParseError::from(BaseConvertError::InvalidDigit(40, 36));
+        assert_eq!(error.kind(), ParseErrorKind::DigitOutOfRange(40));
+        assert_eq!((error.offset(), error.radix()), (None, Some(36)));
+        assert_eq!(error.to_string(), "digit 40 is out of range for radix 36");
+        let error = ParseError::from(BaseConvertError::InvalidBase(1));
+        assert_eq!(error.kind(), ParseErrorKind::InvalidRadix);
+        assert_eq!((error.offset(), error.radix()), (None, Some(1)));
+        assert_eq!(error.to_string(), "invalid radix 1, 2 to 64 are supported");
+        let error = ParseError::from(BaseConvertError::Overflow);
+        assert_eq!(error.kind(), ParseErrorKind::Overflow);
+        assert_eq!((error.offset(), error.radix()), (None, None));
+        assert_eq!(error.to_string(), "value is too large");
+    }
+
+    #[test]
+    fn test_max_digits() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            for radix in 2..=64 {
+                assert_eq!(max_digits(BITS, radix), U::MAX.to_string_radix(radix).len());
+            }
+        });
+        assert_eq!(max_digits(0, 10), 0);
+    }
+
+    #[test]
+    fn test_input_too_long() {
+        type U = Uint<64, 1>;
+        let zeros = "0".repeat(1_000_000);
+        assert_eq!(U::from_str(&format!("{zeros}1")), Ok(U::from(1)));
+        assert_eq!(U::from_str(&format!("0x{zeros}ff")), Ok(U::from(255)));
+        assert_eq!(
+            U::from_str(&format!("1{zeros}")),
+            Err(ParseError::new(ParseErrorKind::InputTooLong(20), 0, 10))
+        );
+        assert_eq!(
+            U::from_str(&format!("0b1{}", "0".repeat(64))),
+            Err(ParseError::new(ParseErrorKind::InputTooLong(64), 2, 2))
+        );
+        assert_eq!(
+            U::from_str_radix(&format!("B{zeros}"), 64),
+            Err(ParseError::new(ParseErrorKind::InputTooLong(11), 0, 64))
+        );
+        assert_eq!(U::from_str_radix(&"A".repeat(1000), 64), Ok(U::ZERO));
+        assert_eq!(
+            U::from_str("99999999999999999999"),
+            Err(ParseError::new(ParseErrorKind::Overflow, 0, 10))
+        );
+        assert_eq!(U::from_str("18446744073709551615"), Ok(U::MAX));
+        assert_eq!(
+            U::from_str_alphabet(&"2".repeat(1000), &Alphabet::BASE58),
+            Err(ParseError::new(ParseErrorKind::InputTooLong(11), 0, 58))
+        );
+        assert_eq!(
+            U::from_str_alphabet(&format!("{}2", "1".repeat(1000)), &Alphabet::BASE58),
+            Ok(U::from(1))
+        );
+    }
+
+    #[test]
+    fn test_parse_options() {
+        type U = Uint<64, 1>;
+        let options = ParseOptions::new();
+        assert_eq!(options, ParseOptions::default());
+        assert_eq!(
+            options.allow_whitespace(true).parse::<64, 1>("\t1e3 "),
+            Ok(U::from(1000))
+        );
+        assert_eq!(
+            options.allow_separators(false).parse::<64, 1>("1_000"),
+            Err(ParseError::new(ParseErrorKind::InvalidDigit('_'), 1, 10))
+        );
+        assert_eq!(
+            options.allow_exponent(false).parse::<64, 1>("1e3"),
+            Err(ParseError::new(ParseErrorKind::InvalidDigit('e'), 1, 10))
+        );
+        assert_eq!(
+            options.allow_whitespace(true).parse::<64, 1>("  0x1g"),
+            Err(ParseError::new(ParseErrorKind::InvalidDigit('g'), 5, 16))
+        );
+        assert_eq!(options.radix(16).parse::<64, 1>("1e3"), Ok(U::from(0x1e3)));
+        assert_eq!(
+            options.radix(16).parse::<64, 1>("0x1"),
+            Err(ParseError::new(ParseErrorKind::BadPrefix, 0, 16))
+        );
+        assert_eq!(options.radix(36).parse::<64, 1>("0x1"), Ok(U::from(1189)));
+        assert_eq!(
+            options
+                .radix(64)
+                .allow_separators(false)
+                .parse::<64, 1>("_"),
+            Ok(U::from(63))
+        );
+        assert_eq!(
+            options.radix(65).parse::<64, 1>("1"),
+            Err(ParseError::new(ParseErrorKind::InvalidRadix, 0, 65))
+        );
+    }
+
+    #[test]
+    fn test_from_str_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, zeros in 0_usize..5)| {
+                assert_eq!(value.to_string().parse::<U>(), Ok(value));
+                assert_eq!(format!("{value:#x}").parse::<U>(), Ok(value));
+                assert_eq!(format!("{value:#o}").parse::<U>(), Ok(value));
+                assert_eq!(format!("{value:#b}").parse::<U>(), Ok(value));
+                let exp = format!("{value:e}");
+                assert_eq!(exp.parse::<U>(), Ok(value));
+                let padded = format!("{value}{}e-{zeros}", "0".repeat(zeros));
+                assert_eq!(padded.parse::<U>(), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_to_string_radix() {
+        assert_eq!(N.to_string_radix(10), N.to_string());
+        assert_eq!(
+            N.to_string_radix(16),
+            "c85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4"
+        );
+        assert_eq!(Uint::<64, 1>::from(35).to_string_radix(36), "z");
+        assert_eq!(Uint::<64, 1>::from(36).to_string_radix(36), "10");
+        assert_eq!(Uint::<64, 1>::from(63).to_string_radix(64), "/");
+        assert_eq!(Uint::<64, 1>::from(32).to_string_radix(64), "g");
+        assert_eq!(Uint::<0, 0>::ZERO.to_string_radix(64), "A");
+        proptest!(|(value: u64, radix in 2_u64..=64)| {
+            let n: Uint<64, 1> = Uint::from(value);
+            let string = n.to_string_radix(radix);
+            assert_eq!(Uint::from_str_radix(&string, radix), Ok(n));
+            if radix == 8 {
+                assert_eq!(string, format!("{value:o}"));
+            }
+        });
+    }
+
+    #[test]
+    fn test_to_string_radix_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, radix in 2_u64..=64)| {
+                let string = value.to_string_radix(radix);
+                assert_eq!(U::from_str_radix(&string, radix), Ok(value));
+            });
+        });
+    }
+}
+
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use ::proptest::{
+        arbitrary::Arbitrary,
+        strategy::{Strategy, ValueTree},
+        test_runner::TestRunner,
+    };
+    use criterion::{black_box, BatchSize, Criterion};
+
+    pub fn group(criterion: &mut Criterion) {
+        const_for!(BITS in BENCH {
+            const LIMBS: usize = nlimbs(BITS);
+            bench_display::<BITS, LIMBS>(criterion);
+            bench_hex::<BITS, LIMBS>(criterion);
+            bench_from_str::<BITS, LIMBS>(criterion);
+        });
+        // Around `DC_THRESHOLD` and well above it.
+        bench_display::<1536, 24>(criterion);
+        bench_display::<1600, 25>(criterion);
+        bench_display::<8192, 128>(criterion);
+        bench_display::<16384, 256>(criterion);
+        bench_display::<32768, 512>(criterion);
+        bench_from_str::<8192, 128>(criterion);
+        bench_from_str::<32768, 512>(criterion);
+    }
+
+    fn bench_display<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+        let input = Uint::<BITS, LIMBS>::arbitrary();
+        let mut runner = TestRunner::deterministic();
+        let mut buffer = String::with_capacity(2000);
+        criterion.bench_function(&format!("display/{BITS}"), move |bencher| {
+            bencher.iter_batched(
+                || input.new_tree(&mut runner).unwrap().current(),
+                |n| {
+                    buffer.clear();
+                    write!(buffer, "{}", black_box(n)).unwrap();
+                    black_box(buffer.len())
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+
+    fn bench_hex<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+        let input = Uint::<BITS, LIMBS>::arbitrary();
+        let mut runner = TestRunner::deterministic();
+        let mut buffer = String::with_capacity(2000);
+        criterion.bench_function(&format!("hex/{BITS}"), move |bencher| {
+            bencher.iter_batched(
+                || input.new_tree(&mut runner).unwrap().current(),
+                |n| {
+                    buffer.clear();
+                    write!(buffer, "{:x}", black_box(n)).unwrap();
+                    black_box(buffer.len())
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+
+    fn bench_from_str<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+        let input = Uint::<BITS, LIMBS>::arbitrary().prop_map(|n| n.to_string());
+        let mut runner = TestRunner::deterministic();
+        criterion.bench_function(&format!("from_str/{BITS}"), move |bencher| {
+            bencher.iter_batched(
+                || input.new_tree(&mut runner).unwrap().current(),
+                |s| black_box(black_box(s).parse::<Uint<BITS, LIMBS>>().unwrap()),
+                BatchSize::SmallInput,
+            );
         });
     }
 }
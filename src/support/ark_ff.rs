@@ -1,11 +1,212 @@
 //! Support for the [`ark-ff`](https://crates.io/crates/ark-ff) crate.
+//!
+//! [`BigInteger`] can not be implemented for [`Uint`] itself. It requires
+//! `From<u64>`, which conflicts with the `TryFrom<u64>` that all sizes
+//! implement. The [`ArkUint`] wrapper implements it instead.
 #![cfg(feature = "ark-ff")]
 #![cfg_attr(has_doc_cfg, doc(cfg(feature = "ark-ff")))]
 
-use crate::{ToFieldError, Uint};
-use ark_ff::{biginteger::*, fields::models::*, PrimeField};
+use crate::{ToFieldError, ToUintError, Uint};
+use ark_ff::{biginteger::*, fields::models::*, FromBytes, PrimeField, ToBytes};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Result as IoResult, Write},
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use num_bigint::BigUint;
+use zeroize::Zeroize;
 
-// FEATURE: Implement the `BigInteger` trait.
+/// Implements [`BigInteger`] for the [`Uint`] sizes that have an arkworks
+/// `BigInteger*` type.
+///
+/// ```
+/// # use ruint::{aliases::U256, ArkUint};
+/// use ark_ff::BigInteger;
+/// let mut value = ArkUint(U256::from(3));
+/// assert!(!value.add_nocarry(&ArkUint::from(4)));
+/// assert_eq!(value.0, U256::from(7));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArkUint<const BITS: usize, const LIMBS: usize>(pub Uint<BITS, LIMBS>);
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for ArkUint<BITS, LIMBS> {
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<ArkUint<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    fn from(value: ArkUint<BITS, LIMBS>) -> Self {
+        value.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Display for ArkUint<BITS, LIMBS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Zeroize for ArkUint<BITS, LIMBS> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> AsRef<[u64]> for ArkUint<BITS, LIMBS> {
+    fn as_ref(&self) -> &[u64] {
+        self.0.as_limbs()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<BigUint> for ArkUint<BITS, LIMBS> {
+    type Error = ToUintError<Uint<BITS, LIMBS>>;
+
+    fn try_from(value: BigUint) -> Result<Self, Self::Error> {
+        Uint::try_from(value).map(Self)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<ArkUint<BITS, LIMBS>> for BigUint {
+    fn from(value: ArkUint<BITS, LIMBS>) -> Self {
+        value.0.into()
+    }
+}
+
+// The serialization and bit conversions delegate to the arkworks type of the
+// same size, so that the encodings are identical.
+macro_rules! impl_big_integer {
+    ($ark:ty, $bits:expr, $limbs:expr) => {
+        impl From<u64> for ArkUint<$bits, $limbs> {
+            fn from(value: u64) -> Self {
+                Self(Uint::from(value))
+            }
+        }
+
+        impl AsMut<[u64]> for ArkUint<$bits, $limbs> {
+            fn as_mut(&mut self) -> &mut [u64] {
+                // SAFETY: All bit patterns are valid, `BITS` is a multiple of 64.
+                unsafe { self.0.as_limbs_mut() }
+            }
+        }
+
+        impl Distribution<ArkUint<$bits, $limbs>> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ArkUint<$bits, $limbs> {
+                ArkUint(Uint::from_limbs(rng.gen()))
+            }
+        }
+
+        impl ToBytes for ArkUint<$bits, $limbs> {
+            fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+                <$ark>::from(self.0).write(writer)
+            }
+        }
+
+        impl FromBytes for ArkUint<$bits, $limbs> {
+            fn read<R: Read>(reader: R) -> IoResult<Self> {
+                <$ark>::read(reader).map(|value| Self(value.into()))
+            }
+        }
+
+        impl CanonicalSerialize for ArkUint<$bits, $limbs> {
+            fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+                <$ark>::from(self.0).serialize(writer)
+            }
+
+            fn serialized_size(&self) -> usize {
+                <$ark>::from(self.0).serialized_size()
+            }
+        }
+
+        impl CanonicalDeserialize for ArkUint<$bits, $limbs> {
+            fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+                <$ark>::deserialize(reader).map(|value| Self(value.into()))
+            }
+        }
+
+        impl BigInteger for ArkUint<$bits, $limbs> {
+            const NUM_LIMBS: usize = $limbs;
+
+            fn add_nocarry(&mut self, other: &Self) -> bool {
+                let carry;
+                (self.0, carry) = self.0.overflowing_add(other.0);
+                carry
+            }
+
+            fn sub_noborrow(&mut self, other: &Self) -> bool {
+                let borrow;
+                (self.0, borrow) = self.0.overflowing_sub(other.0);
+                borrow
+            }
+
+            fn mul2(&mut self) {
+                self.0 <<= 1;
+            }
+
+            fn muln(&mut self, amt: u32) {
+                self.0 <<= amt as usize;
+            }
+
+            fn div2(&mut self) {
+                self.0 >>= 1;
+            }
+
+            fn divn(&mut self, amt: u32) {
+                self.0 >>= amt as usize;
+            }
+
+            fn is_odd(&self) -> bool {
+                self.0.bit(0)
+            }
+
+            fn is_even(&self) -> bool {
+                !self.0.bit(0)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == Uint::ZERO
+            }
+
+            #[allow(clippy::cast_possible_truncation)] // At most 832
+            fn num_bits(&self) -> u32 {
+                self.0.bit_len() as u32
+            }
+
+            fn get_bit(&self, i: usize) -> bool {
+                self.0.bit(i)
+            }
+
+            fn from_bits_be(bits: &[bool]) -> Self {
+                Self(<$ark>::from_bits_be(bits).into())
+            }
+
+            fn from_bits_le(bits: &[bool]) -> Self {
+                Self(<$ark>::from_bits_le(bits).into())
+            }
+
+            fn to_bytes_be(&self) -> Vec<u8> {
+                self.0.to_be_bytes_vec()
+            }
+
+            fn to_bytes_le(&self) -> Vec<u8> {
+                self.0.to_le_bytes_vec()
+            }
+        }
+    };
+}
+
+impl_big_integer!(BigInteger64, 64, 1);
+impl_big_integer!(BigInteger128, 128, 2);
+impl_big_integer!(BigInteger256, 256, 4);
+impl_big_integer!(BigInteger320, 320, 5);
+impl_big_integer!(BigInteger384, 384, 6);
+impl_big_integer!(BigInteger448, 448, 7);
+impl_big_integer!(BigInteger768, 768, 12);
+impl_big_integer!(BigInteger832, 832, 13);
 
 macro_rules! impl_from_ark {
     ($ark:ty, $bits:expr, $limbs:expr) => {
@@ -84,12 +285,54 @@ impl_from_ark_field!(Fp448, Fp448Parameters, 448, 7);
 impl_from_ark_field!(Fp768, Fp768Parameters, 768, 12);
 impl_from_ark_field!(Fp832, Fp832Parameters, 832, 13);
 
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Converts the value to an element of any arkworks [`PrimeField`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{aliases::U256, ToFieldError};
+    /// use ark_bn254::Fr;
+    /// let field: Fr = U256::from(42).to_field().unwrap();
+    /// assert_eq!(field, Fr::from(42));
+    /// assert_eq!(U256::MAX.to_field::<Fr>(), Err(ToFieldError::NotInField));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToFieldError::NotInField`] if the value is not less than the
+    /// modulus.
+    pub fn to_field<F: PrimeField>(&self) -> Result<F, ToFieldError> {
+        let mut repr = F::BigInt::default();
+        let limbs = repr.as_mut();
+        let (low, high) = self.as_limbs().split_at(LIMBS.min(limbs.len()));
+        if high.iter().any(|&limb| limb != 0) {
+            return Err(ToFieldError::NotInField);
+        }
+        limbs[..low.len()].copy_from_slice(low);
+        F::from_repr(repr).ok_or(ToFieldError::NotInField)
+    }
+
+    /// Converts an element of any arkworks [`PrimeField`] to its canonical
+    /// value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToUintError::ValueTooLarge`] if the value does not fit.
+    pub fn from_field<F: PrimeField>(field: &F) -> Result<Self, ToUintError<Self>> {
+        match Self::overflowing_from_limbs_slice(field.into_repr().as_ref()) {
+            (n, false) => Ok(n),
+            (n, true) => Err(ToUintError::ValueTooLarge(BITS, n)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::aliases::U256;
     use ark_bn254::{Fq, FqParameters, Fr, FrParameters};
-    use ark_ff::FpParameters;
+    use ark_ff::{FpParameters, UniformRand};
     use proptest::proptest;
 
     macro_rules! test_roundtrip {
@@ -114,6 +357,77 @@ mod tests {
         test_roundtrip!(BigInteger832, 832, 13);
     }
 
+    macro_rules! test_big_integer {
+        ($ark:ty, $bits:expr, $limbs:expr) => {
+            proptest!(|(a: Uint<$bits, $limbs>, b: Uint<$bits, $limbs>, n in 0_u32..900)| {
+                let (ours, theirs) = (ArkUint(a), <$ark>::from(a));
+                let same = |ours: ArkUint<$bits, $limbs>, theirs: $ark| {
+                    let theirs: Uint<$bits, $limbs> = theirs.into();
+                    assert_eq!(ours.0, theirs);
+                };
+                let (mut x, mut y) = (ours, theirs);
+                assert_eq!(x.add_nocarry(&ArkUint(b)), y.add_nocarry(&b.into()));
+                same(x, y);
+                let (mut x, mut y) = (ours, theirs);
+                assert_eq!(x.sub_noborrow(&ArkUint(b)), y.sub_noborrow(&b.into()));
+                same(x, y);
+                let (mut x, mut y) = (ours, theirs);
+                x.mul2();
+                y.mul2();
+                same(x, y);
+                let (mut x, mut y) = (ours, theirs);
+                x.muln(n);
+                y.muln(n);
+                same(x, y);
+                let (mut x, mut y) = (ours, theirs);
+                x.div2();
+                y.div2();
+                same(x, y);
+                let (mut x, mut y) = (ours, theirs);
+                x.divn(n);
+                y.divn(n);
+                same(x, y);
+                assert_eq!(ours.is_odd(), theirs.is_odd());
+                assert_eq!(ours.is_zero(), theirs.is_zero());
+                assert_eq!(ours.num_bits(), theirs.num_bits());
+                assert_eq!(ours.get_bit(n as usize), theirs.get_bit(n as usize));
+                assert_eq!(ours.to_bits_be(), theirs.to_bits_be());
+                same(ArkUint::from_bits_le(&theirs.to_bits_le()), theirs);
+                assert_eq!(ours.to_bytes_be(), theirs.to_bytes_be());
+                assert_eq!(ours.to_bytes_le(), theirs.to_bytes_le());
+                assert_eq!(ours.find_wnaf(3), theirs.find_wnaf(3));
+                let mut bytes = Vec::new();
+                ours.serialize(&mut bytes).unwrap();
+                let mut expected = Vec::new();
+                theirs.serialize(&mut expected).unwrap();
+                assert_eq!(bytes, expected);
+                assert_eq!(ours.serialized_size(), bytes.len());
+                assert_eq!(ArkUint::deserialize(bytes.as_slice()).unwrap(), ours);
+                let big: BigUint = ours.into();
+                assert_eq!(big, theirs.into());
+                assert_eq!(ArkUint::try_from(big), Ok(ours));
+            });
+            let mut rng = ark_std::test_rng();
+            let ours = ArkUint::<$bits, $limbs>::rand(&mut rng);
+            let mut rng = ark_std::test_rng();
+            let theirs: Uint<$bits, $limbs> = <$ark>::rand(&mut rng).into();
+            assert_eq!(ours.0, theirs);
+            assert_eq!(ArkUint::<$bits, $limbs>::from(7).0, Uint::from(7));
+        };
+    }
+
+    #[test]
+    fn test_big_integer() {
+        test_big_integer!(BigInteger64, 64, 1);
+        test_big_integer!(BigInteger128, 128, 2);
+        test_big_integer!(BigInteger256, 256, 4);
+        test_big_integer!(BigInteger320, 320, 5);
+        test_big_integer!(BigInteger384, 384, 6);
+        test_big_integer!(BigInteger448, 448, 7);
+        test_big_integer!(BigInteger768, 768, 12);
+        test_big_integer!(BigInteger832, 832, 13);
+    }
+
     #[test]
     fn test_fq_roundtrip() {
         let modulus: U256 = FqParameters::MODULUS.into();
@@ -125,6 +439,37 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_to_field() {
+        type U = Uint<254, 4>;
+        let modulus: U256 = FrParameters::MODULUS.into();
+        proptest!(|(value: U)| {
+            match value.to_field::<Fr>() {
+                Ok(field) => assert_eq!(U::from_field(&field), Ok(value)),
+                Err(_) => assert!(U256::from(value) >= modulus),
+            }
+        });
+        proptest!(|(value: U256)| {
+            let value = value % modulus;
+            let field: Fr = value.to_field().unwrap();
+            assert_eq!(field, Fr::try_from(value).unwrap());
+            assert_eq!(U256::from_field(&field), Ok(value));
+            assert_eq!(Uint::<512, 8>::from(value).to_field(), Ok(field));
+        });
+        assert_eq!(modulus.to_field::<Fr>(), Err(ToFieldError::NotInField));
+        assert_eq!(
+            (Uint::<512, 8>::from(1) << 256_usize).to_field::<Fr>(),
+            Err(ToFieldError::NotInField)
+        );
+        assert_eq!(Uint::<64, 1>::from_field(&Fr::from(7)), Ok(Uint::from(7)));
+        let field = Fr::from(u64::MAX) * Fr::from(3);
+        assert!(matches!(
+            Uint::<64, 1>::from_field(&field),
+            Err(ToUintError::ValueTooLarge(64, _))
+        ));
+        assert_eq!(Uint::<0, 0>::from_field(&Fr::from(0)), Ok(Uint::ZERO));
+    }
+
     #[test]
     fn test_fr_roundtrip() {
         let modulus: U256 = FrParameters::MODULUS.into();
@@ -0,0 +1,90 @@
+//! Support for the [`bigdecimal`](https://crates.io/crates/bigdecimal) crate.
+#![cfg(feature = "bigdecimal")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "bigdecimal")))]
+
+use super::decimal::{private::Sealed, DecimalError, DecimalValue};
+use bigdecimal::{
+    num_bigint::{BigInt, BigUint, Sign},
+    BigDecimal,
+};
+
+impl Sealed for BigDecimal {}
+
+impl DecimalValue for BigDecimal {
+    fn to_decimal_parts(&self) -> (bool, Vec<u8>, i64) {
+        let (mantissa, scale) = self.as_bigint_and_exponent();
+        let digits = mantissa.magnitude().to_radix_be(10);
+        (mantissa.sign() == Sign::Minus, digits, scale)
+    }
+
+    fn from_decimal_parts(digits: Vec<u8>, scale: u32) -> Result<Self, DecimalError> {
+        let mantissa = BigUint::from_radix_be(&digits, 10).unwrap_or_default();
+        Ok(Self::new(BigInt::from(mantissa), scale.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs, Rounding, Uint};
+    use proptest::proptest;
+
+    fn decimal(s: &str) -> BigDecimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_from_decimal() {
+        assert_eq!(
+            U256::from_decimal(&decimal("1.5"), 18),
+            Ok(U256::from(1_500_000_000_000_000_000_u64))
+        );
+        assert_eq!(
+            U256::from_decimal(&decimal("15e17"), 0),
+            Ok(U256::from(15) * U256::from(10).pow(17))
+        );
+        assert_eq!(
+            U256::from_decimal(&decimal("1e78"), 0),
+            Err(DecimalError::Overflow)
+        );
+        assert_eq!(
+            U256::from_decimal(&decimal("123.456"), 2),
+            Err(DecimalError::Inexact)
+        );
+        assert_eq!(
+            U256::from_decimal_rounded(&decimal("123.456"), 2, Rounding::HalfEven),
+            Ok(U256::from(12346))
+        );
+        assert_eq!(
+            U256::from_decimal(&decimal("-0.5"), 1),
+            Err(DecimalError::Negative)
+        );
+        assert_eq!(U256::from_decimal(&decimal("0e-1000"), 0), Ok(U256::ZERO));
+    }
+
+    #[test]
+    fn test_to_decimal() {
+        assert_eq!(
+            U256::from(1_500_000_000_000_000_000_u64).to_decimal(18),
+            Ok(decimal("1.5"))
+        );
+        assert_eq!(
+            U256::MAX.to_decimal(1000),
+            Ok(decimal(&format!("{}e-1000", U256::MAX)))
+        );
+        assert_eq!(U256::ZERO.to_decimal(10), Ok(BigDecimal::default()));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, scale in 0_u32..100)| {
+                let decimal: BigDecimal = value.to_decimal(scale).unwrap();
+                assert_eq!(decimal.to_string().parse::<BigDecimal>().unwrap(), decimal);
+                assert_eq!(U::from_decimal(&decimal, scale), Ok(value));
+            });
+        });
+    }
+}
@@ -0,0 +1,47 @@
+//! Support for the [`bnum`](https://crates.io/crates/bnum) crate.
+//!
+//! Conversions are provided between [`BUint<N>`](BUint) and
+//! `Uint<{ 64 * N }, N>` for `N` from 1 to 16, 32 and 64.
+#![cfg(feature = "bnum")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "bnum")))]
+
+use crate::Uint;
+use bnum::BUint;
+
+macro_rules! impl_froms {
+    ($($limbs:literal)*) => {$(
+        impl From<BUint<$limbs>> for Uint<{ 64 * $limbs }, $limbs> {
+            fn from(value: BUint<$limbs>) -> Self {
+                Self::from_limbs(*value.digits())
+            }
+        }
+
+        impl From<Uint<{ 64 * $limbs }, $limbs>> for BUint<$limbs> {
+            fn from(value: Uint<{ 64 * $limbs }, $limbs>) -> Self {
+                Self::from_digits(value.into_limbs())
+            }
+        }
+    )*};
+}
+
+impl_froms!(1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 32 64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::const_for;
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(LIMBS in [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 32, 64] {
+            type U = Uint<{ 64 * LIMBS }, LIMBS>;
+            proptest!(|(value: U)| {
+                let theirs: BUint<LIMBS> = value.into();
+                assert_eq!(theirs.to_str_radix(16), format!("{value:x}"));
+                let ours: U = theirs.into();
+                assert_eq!(ours, value);
+            });
+        });
+    }
+}
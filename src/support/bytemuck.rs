@@ -0,0 +1,116 @@
+//! Support for the [`bytemuck`](https://crates.io/crates/bytemuck) crate.
+//!
+//! [`Uint`] is `#[repr(transparent)]` over `[u64; LIMBS]`, so every [`Uint`]
+//! is [`Zeroable`]. When `BITS == 64 * LIMBS` all bit patterns are valid and
+//! it is [`Pod`]. Other sizes implement [`CheckedBitPattern`] which rejects
+//! values with bits set above `BITS`.
+//!
+//! Rust can not yet express these bounds on const generics, so the impls are
+//! provided for a fixed set of sizes:
+//!
+//! * [`Pod`] for `LIMBS` from 0 to 16, 32 and 64.
+//! * [`CheckedBitPattern`] for `BITS` from 1 to 63 and for multiples of eight
+//!   up to 512 bits.
+//!
+//! ```
+//! # use ruint::aliases::U256;
+//! let values = [U256::from(1), U256::MAX];
+//! let bytes: &[u8] = bytemuck::cast_slice(&values);
+//! assert_eq!(bytes.len(), 64);
+//! assert_eq!(bytemuck::cast_slice::<u8, U256>(bytes), values);
+//! ```
+#![cfg(feature = "bytemuck")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "bytemuck")))]
+
+use crate::Uint;
+use bytemuck::{CheckedBitPattern, Pod, Zeroable};
+
+// Safety: the only field is `[u64; LIMBS]` for which zero is valid.
+unsafe impl<const BITS: usize, const LIMBS: usize> Zeroable for Uint<BITS, LIMBS> {}
+
+macro_rules! impl_pod {
+    ($($limbs:literal)*) => {
+        $(
+            // Safety: `#[repr(transparent)]` over `[u64; LIMBS]` and without
+            // unused high bits every bit pattern is valid.
+            unsafe impl Pod for Uint<{ 64 * $limbs }, $limbs> {}
+        )*
+    };
+}
+
+impl_pod!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 32 64);
+
+macro_rules! impl_checked_bit_pattern {
+    ($limbs:literal; $($bits:literal)*) => {
+        $(
+            // Safety: `[u64; LIMBS]` has the same layout and the value is
+            // valid when the bits above `BITS` are zero.
+            unsafe impl CheckedBitPattern for Uint<$bits, $limbs> {
+                type Bits = [u64; $limbs];
+
+                fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+                    bits[$limbs - 1] <= Self::MASK
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_bit_pattern!(1;
+    1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33
+    34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+);
+impl_checked_bit_pattern!(2; 72 80 88 96 104 112 120);
+impl_checked_bit_pattern!(3; 136 144 152 160 168 176 184);
+impl_checked_bit_pattern!(4; 200 208 216 224 232 240 248);
+impl_checked_bit_pattern!(5; 264 272 280 288 296 304 312);
+impl_checked_bit_pattern!(6; 328 336 344 352 360 368 376);
+impl_checked_bit_pattern!(7; 392 400 408 416 424 432 440);
+impl_checked_bit_pattern!(8; 456 464 472 480 488 496 504);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::{U128, U16, U256, U64};
+    use bytemuck::checked::{self, CheckedCastError};
+    use proptest::proptest;
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(core::mem::size_of::<U256>(), 32);
+        assert_eq!(core::mem::align_of::<U256>(), 8);
+        assert_eq!(bytemuck::cast::<_, [u64; 4]>(U256::from(1) << 64), [
+            0, 1, 0, 0
+        ]);
+        assert_eq!(Uint::<160, 3>::zeroed(), Uint::ZERO);
+    }
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(checked::try_cast::<u64, U16>(0xffff), Ok(U16::from(0xffff)));
+        assert_eq!(
+            checked::try_cast::<u64, U16>(0x1_0000),
+            Err(CheckedCastError::InvalidBitPattern)
+        );
+        assert_eq!(
+            checked::try_cast::<[u64; 3], Uint<160, 3>>([0, 0, 1 << 32]),
+            Err(CheckedCastError::InvalidBitPattern)
+        );
+    }
+
+    #[test]
+    fn test_pod_roundtrip() {
+        proptest!(|(values: Vec<U128>)| {
+            let limbs: &[u64] = bytemuck::cast_slice(&values);
+            for (value, chunk) in values.iter().zip(limbs.chunks_exact(2)) {
+                assert_eq!(chunk, value.as_limbs());
+            }
+            let bytes: &[u8] = bytemuck::cast_slice(&values);
+            assert_eq!(bytes.len(), 16 * values.len());
+            assert_eq!(bytemuck::cast_slice::<u8, U128>(bytes), values);
+        });
+        proptest!(|(value: U64)| {
+            assert_eq!(bytemuck::cast::<_, u64>(value), value.as_limbs()[0]);
+        });
+    }
+}
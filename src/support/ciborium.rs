@@ -0,0 +1,400 @@
+//! Support for the [`ciborium`](https://crates.io/crates/ciborium) crate.
+//!
+//! Encodes a [`Uint`] as a [CBOR] integer. Values that fit in a `u64` are
+//! encoded as major type 0 (unsigned integer) and larger values as a tag 2
+//! (unsigned bignum) over the big-endian bytes. This is the same encoding
+//! `ciborium` uses for `u128`.
+//!
+//! Decoding accepts both forms but requires the shortest form: bignums may not
+//! have leading zero bytes and may not hold values that fit in a `u64`. Tag 3
+//! (negative bignum) and negative integers are rejected.
+//!
+//! Serde does not expose the width of an item head, so [`deserialize`] can not
+//! reject an integer in a longer head than needed, like `1` encoded as
+//! `0x1b0000000000000001`. Use [`from_slice`] to decode a single item with all
+//! heads in the shortest form.
+//!
+//! The generic [`serde`](crate#feature-flags) implementation for [`Uint`]
+//! encodes a plain byte string instead. Use this module with `serde`'s `with`
+//! attribute to select the CBOR integer encoding:
+//!
+//! ```
+//! # use ruint::{aliases::U256, uint};
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Transfer {
+//!     #[serde(with = "ruint::cbor")]
+//!     amount: U256,
+//! }
+//!
+//! let transfer = Transfer {
+//!     amount: uint!(42_U256),
+//! };
+//! let mut buffer = Vec::new();
+//! ciborium::ser::into_writer(&transfer, &mut buffer).unwrap();
+//! // Map with one entry "amount" to the unsigned integer 42.
+//! assert_eq!(buffer, b"\xa1\x66amount\x18\x2a");
+//! ```
+//!
+//! [CBOR]: https://www.rfc-editor.org/rfc/rfc8949.html#name-bignums
+#![cfg(feature = "ciborium")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "ciborium")))]
+
+use crate::Uint;
+use ciborium::tag::Captured;
+use core::fmt::{Formatter, Result as FmtResult};
+use serde::{
+    de::{Error, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use thiserror::Error;
+
+/// Error for [`from_slice`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum CborError {
+    /// The item is not an unsigned integer or a tagged byte string.
+    ///
+    /// `.0` is the major type.
+    #[error("expected a CBOR unsigned integer or bignum, found major type {0}")]
+    UnexpectedType(u8),
+
+    /// The item has a tag other than an unsigned bignum.
+    #[error("unexpected CBOR tag {0}")]
+    UnexpectedTag(u64),
+
+    /// The input ended before the end of the item.
+    #[error("CBOR item is truncated")]
+    Truncated,
+
+    /// There are bytes left after the item.
+    #[error("CBOR item has trailing bytes")]
+    TrailingBytes,
+
+    /// A head or the bignum is not in the shortest form.
+    #[error("CBOR item is not in the shortest form")]
+    NonCanonical,
+
+    /// The value is a negative integer or bignum.
+    #[error("CBOR value is negative")]
+    Negative,
+
+    /// The value is too large to fit the target type.
+    ///
+    /// `.0` is `BITS`.
+    #[error("CBOR value is too large for Uint<{0}>")]
+    Overflow(usize),
+}
+
+/// CBOR major type of an unsigned integer.
+const MAJOR_UNSIGNED: u8 = 0;
+
+/// CBOR major type of a negative integer.
+const MAJOR_NEGATIVE: u8 = 1;
+
+/// CBOR major type of a byte string.
+const MAJOR_BYTES: u8 = 2;
+
+/// CBOR major type of a tag.
+const MAJOR_TAG: u8 = 6;
+
+/// CBOR tag for an unsigned bignum.
+const BIGNUM_POSITIVE: u64 = 2;
+
+/// CBOR tag for a negative bignum.
+const BIGNUM_NEGATIVE: u64 = 3;
+
+/// Serialize a [`Uint`] as a CBOR integer or tag 2 bignum.
+///
+/// # Errors
+///
+/// Returns an error if the underlying serializer fails.
+pub fn serialize<S, const BITS: usize, const LIMBS: usize>(
+    value: &Uint<BITS, LIMBS>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if let Ok(value) = u64::try_from(value) {
+        serializer.serialize_u64(value)
+    } else {
+        let bytes = value.to_be_bytes_trimmed_vec();
+        Captured(Some(BIGNUM_POSITIVE), Bytes(&bytes)).serialize(serializer)
+    }
+}
+
+/// Deserialize a [`Uint`] from a CBOR integer or tag 2 bignum.
+///
+/// # Errors
+///
+/// Returns an error if the value is negative, not an integer, not in the
+/// shortest form or too large for the [`Uint`].
+pub fn deserialize<'de, D, const BITS: usize, const LIMBS: usize>(
+    deserializer: D,
+) -> Result<Uint<BITS, LIMBS>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Captured(tag, item) = Captured::<Item>::deserialize(deserializer)?;
+    match (tag, item) {
+        (None, Item::Integer(value)) => Uint::try_from(value).map_err(|_| {
+            D::Error::invalid_value(Unexpected::Unsigned(value), &"a smaller integer")
+        }),
+        (Some(BIGNUM_POSITIVE), Item::Bytes(bytes)) => {
+            if bytes.first() == Some(&0) {
+                return Err(D::Error::custom("bignum has leading zero bytes"));
+            }
+            if bytes.len() <= 8 {
+                return Err(D::Error::custom("bignum value should be an integer"));
+            }
+            Uint::try_from_be_slice(&bytes)
+                .ok_or_else(|| D::Error::custom(format!("value too large for Uint<{BITS}>")))
+        }
+        (Some(BIGNUM_NEGATIVE), _) => Err(D::Error::custom("negative bignum")),
+        (Some(tag), _) => Err(D::Error::custom(format!("unexpected tag {tag}"))),
+        (None, Item::Bytes(bytes)) => Err(D::Error::invalid_type(
+            Unexpected::Bytes(&bytes),
+            &"an integer or bignum",
+        )),
+    }
+}
+
+/// Decodes a single CBOR integer or tag 2 bignum, with every head in the
+/// shortest form.
+///
+/// # Errors
+///
+/// * [`CborError::UnexpectedType`] if the item is not an integer or a tagged
+///   byte string.
+/// * [`CborError::UnexpectedTag`] if the tag is not an unsigned bignum.
+/// * [`CborError::Truncated`] if the input is shorter than the item.
+/// * [`CborError::TrailingBytes`] if the input is longer than the item.
+/// * [`CborError::NonCanonical`] if a head is longer than needed, the length is
+///   indefinite, or the bignum has leading zeros or fits in a `u64`.
+/// * [`CborError::Negative`] if the value is negative.
+/// * [`CborError::Overflow`] if the value does not fit the [`Uint`].
+pub fn from_slice<const BITS: usize, const LIMBS: usize>(
+    bytes: &[u8],
+) -> Result<Uint<BITS, LIMBS>, CborError> {
+    let (major, argument, rest) = split_head(bytes)?;
+    match major {
+        MAJOR_UNSIGNED => {
+            if !rest.is_empty() {
+                return Err(CborError::TrailingBytes);
+            }
+            Uint::try_from(argument).map_err(|_| CborError::Overflow(BITS))
+        }
+        MAJOR_NEGATIVE => Err(CborError::Negative),
+        MAJOR_TAG => match argument {
+            BIGNUM_POSITIVE => {
+                let (major, length, rest) = split_head(rest)?;
+                if major != MAJOR_BYTES {
+                    return Err(CborError::UnexpectedType(major));
+                }
+                // A length that does not fit a `usize` can never be satisfied.
+                let length = usize::try_from(length).map_err(|_| CborError::Truncated)?;
+                if rest.len() < length {
+                    return Err(CborError::Truncated);
+                }
+                if rest.len() > length {
+                    return Err(CborError::TrailingBytes);
+                }
+                if rest.first() == Some(&0) || length <= 8 {
+                    return Err(CborError::NonCanonical);
+                }
+                Uint::try_from_be_slice(rest).ok_or(CborError::Overflow(BITS))
+            }
+            BIGNUM_NEGATIVE => Err(CborError::Negative),
+            tag => Err(CborError::UnexpectedTag(tag)),
+        },
+        major => Err(CborError::UnexpectedType(major)),
+    }
+}
+
+/// Splits an item head in the shortest form from the start of `bytes`,
+/// returning the major type and the argument.
+fn split_head(bytes: &[u8]) -> Result<(u8, u64, &[u8]), CborError> {
+    let (&initial, rest) = bytes.split_first().ok_or(CborError::Truncated)?;
+    let (major, info) = (initial >> 5, initial & 0x1f);
+    let size = match info {
+        0..=23 => return Ok((major, u64::from(info), rest)),
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        // Reserved values and indefinite lengths.
+        _ => return Err(CborError::NonCanonical),
+    };
+    if rest.len() < size {
+        return Err(CborError::Truncated);
+    }
+    let (argument, rest) = rest.split_at(size);
+    let mut buffer = [0; 8];
+    buffer[8 - size..].copy_from_slice(argument);
+    let argument = u64::from_be_bytes(buffer);
+    // The argument must not fit in a shorter head.
+    let minimum = if size == 1 { 24 } else { 1 << (4 * size) };
+    if argument < minimum {
+        return Err(CborError::NonCanonical);
+    }
+    Ok((major, argument, rest))
+}
+
+/// Serializes a byte string.
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// The data item following an optional tag.
+enum Item {
+    Integer(u64),
+    Bytes(Vec<u8>),
+}
+
+impl<'de> Deserialize<'de> for Item {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ItemVisitor)
+    }
+}
+
+struct ItemVisitor;
+
+impl Visitor<'_> for ItemVisitor {
+    type Value = Item;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(formatter, "a CBOR unsigned integer or bignum")
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Item::Integer(value))
+    }
+
+    fn visit_bytes<E: Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        Ok(Item::Bytes(value.to_vec()))
+    }
+
+    fn visit_byte_buf<E: Error>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(Item::Bytes(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use hex_literal::hex;
+    use proptest::proptest;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper<const BITS: usize, const LIMBS: usize>(
+        #[serde(with = "super")] Uint<BITS, LIMBS>,
+    );
+
+    fn encode<const BITS: usize, const LIMBS: usize>(value: Uint<BITS, LIMBS>) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        ciborium::ser::into_writer(&Wrapper(value), &mut buffer).unwrap();
+        buffer
+    }
+
+    fn decode<const BITS: usize, const LIMBS: usize>(bytes: &[u8]) -> Option<Uint<BITS, LIMBS>> {
+        ciborium::de::from_reader::<Wrapper<BITS, LIMBS>, _>(bytes)
+            .ok()
+            .map(|wrapper| wrapper.0)
+    }
+
+    #[test]
+    fn test_cbor() {
+        // See <https://www.rfc-editor.org/rfc/rfc8949.html#name-examples-of-encoded-cbor-d>
+        assert_eq!(encode(U256::from(0)), hex!("00"));
+        assert_eq!(encode(U256::from(23)), hex!("17"));
+        assert_eq!(encode(U256::from(24)), hex!("1818"));
+        assert_eq!(encode(U256::from(1_000_000)), hex!("1a000f4240"));
+        assert_eq!(encode(U256::from(u64::MAX)), hex!("1bffffffffffffffff"));
+        assert_eq!(
+            encode(U256::from(u64::MAX) + U256::from(1)),
+            hex!("c249010000000000000000")
+        );
+        assert_eq!(
+            decode(&hex!("c249010000000000000000")),
+            Some(U256::from(u64::MAX) + U256::from(1))
+        );
+        assert_eq!(decode(&hex!("1a000f4240")), Some(U256::from(1_000_000)));
+    }
+
+    #[test]
+    fn test_cbor_invalid() {
+        use CborError::*;
+        for (bytes, error) in [
+            // Negative integer and bignum.
+            (&hex!("20")[..], Negative),
+            (&hex!("c349010000000000000000"), Negative),
+            // Leading zeros and bignums that should be integers.
+            (&hex!("c24a00010000000000000000"), NonCanonical),
+            (&hex!("c24101"), NonCanonical),
+            // Plain byte strings and other tags.
+            (&hex!("49010000000000000000"), UnexpectedType(MAJOR_BYTES)),
+            (&hex!("c149010000000000000000"), UnexpectedTag(1)),
+        ] {
+            assert_eq!(decode::<256, 4>(bytes), None);
+            assert_eq!(from_slice::<256, 4>(bytes), Err(error));
+        }
+        // Too large.
+        assert_eq!(decode::<64, 1>(&hex!("c249010000000000000000")), None);
+        assert_eq!(decode::<8, 1>(&hex!("190100")), None);
+        assert_eq!(
+            from_slice::<64, 1>(&hex!("c249010000000000000000")),
+            Err(Overflow(64))
+        );
+        assert_eq!(from_slice::<8, 1>(&hex!("190100")), Err(Overflow(8)));
+        // Heads longer than needed, which only `from_slice` can see.
+        for bytes in [
+            &hex!("1b0000000000000001")[..],
+            &hex!("1817"),
+            &hex!("1900ff"),
+            &hex!("1a0000ffff"),
+            &hex!("1b00000000ffffffff"),
+            &hex!("c25a0000000901000000000000000000"),
+            &hex!("d80249010000000000000000"),
+        ] {
+            assert_eq!(from_slice::<256, 4>(bytes), Err(NonCanonical));
+        }
+        // Truncated and trailing bytes.
+        assert_eq!(from_slice::<256, 4>(&hex!("")), Err(Truncated));
+        assert_eq!(from_slice::<256, 4>(&hex!("1a000f42")), Err(Truncated));
+        assert_eq!(from_slice::<256, 4>(&hex!("c2490100")), Err(Truncated));
+        assert_eq!(from_slice::<256, 4>(&hex!("0000")), Err(TrailingBytes));
+        assert_eq!(
+            from_slice::<256, 4>(&hex!("c24901000000000000000000")),
+            Err(TrailingBytes)
+        );
+    }
+
+    #[test]
+    fn test_cbor_u128_compat() {
+        proptest!(|(value: u128)| {
+            let mut expected = Vec::new();
+            ciborium::ser::into_writer(&value, &mut expected).unwrap();
+            let serialized = encode(Uint::<128, 2>::from(value));
+            assert_eq!(serialized, expected);
+            let deserialized: u128 = ciborium::de::from_reader(&serialized[..]).unwrap();
+            assert_eq!(deserialized, value);
+        });
+    }
+
+    #[test]
+    fn test_cbor_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let encoded = encode(value);
+                assert_eq!(decode(&encoded), Some(value));
+                assert_eq!(from_slice(&encoded), Ok(value));
+            });
+        });
+    }
+}
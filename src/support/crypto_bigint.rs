@@ -0,0 +1,56 @@
+//! Support for the [`crypto-bigint`](https://crates.io/crates/crypto-bigint) crate.
+//!
+//! Conversions are provided for all sizes that have an alias in
+//! `crypto-bigint`, from [`U64`] to [`U32768`]. They go through the
+//! little-endian bytes, so they are independent of the limb size of the
+//! platform.
+#![cfg(feature = "crypto-bigint")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "crypto-bigint")))]
+
+use crate::Uint;
+use crypto_bigint::{Encoding, *};
+
+macro_rules! impl_froms {
+    ($($theirs:ident $bits:literal),*) => {$(
+        impl From<$theirs> for Uint<$bits, { $bits / 64 }> {
+            fn from(value: $theirs) -> Self {
+                Self::from_le_bytes(value.to_le_bytes())
+            }
+        }
+
+        impl From<Uint<$bits, { $bits / 64 }>> for $theirs {
+            fn from(value: Uint<$bits, { $bits / 64 }>) -> Self {
+                Self::from_le_bytes(value.to_le_bytes())
+            }
+        }
+    )*};
+}
+
+impl_froms!(
+    U64 64, U128 128, U192 192, U256 256, U320 320, U384 384, U448 448, U512 512, U576 576,
+    U640 640, U704 704, U768 768, U832 832, U896 896, U960 960, U1024 1024, U1280 1280,
+    U1536 1536, U1792 1792, U2048 2048, U3072 3072, U3584 3584, U4096 4096, U4224 4224,
+    U4352 4352, U6144 6144, U8192 8192, U16384 16384, U32768 32768
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases as ours, const_for};
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in [64, 128, 192, 256, 320, 384, 448, 512, 1024, 2048, 4096] {
+            type U = Uint<BITS, { BITS / 64 }>;
+            proptest!(|(value: U)| {
+                let theirs: crypto_bigint::Uint<{ nlimbs!(BITS) }> = value.into();
+                assert_eq!(theirs.to_le_bytes(), value.to_le_bytes::<{ BITS / 8 }>());
+                let ours: U = theirs.into();
+                assert_eq!(ours, value);
+            });
+        });
+        let value = ours::U256::from(0x1234_u64) << 200_usize;
+        assert_eq!(U256::from(value), U256::from_u64(0x1234).shl_vartime(200));
+    }
+}
@@ -0,0 +1,138 @@
+//! Support for the [`dashu-int`](https://crates.io/crates/dashu-int) crate.
+#![cfg(feature = "dashu-int")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "dashu-int")))]
+
+use crate::{from::ToUintError, Uint};
+use dashu_int::{ops::UnsignedAbs, IBig, Sign, UBig};
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<UBig> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    fn try_from(value: UBig) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&UBig> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    fn try_from(value: &UBig) -> Result<Self, Self::Error> {
+        let (n, overflow) = from_ubig(value);
+        if overflow {
+            Err(ToUintError::ValueTooLarge(BITS, n))
+        } else {
+            Ok(n)
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for UBig {
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for UBig {
+    fn from(value: &Uint<BITS, LIMBS>) -> Self {
+        Self::from_le_bytes(&value.as_le_bytes())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<IBig> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    fn try_from(value: IBig) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&IBig> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    fn try_from(value: &IBig) -> Result<Self, Self::Error> {
+        let (n, overflow) = from_ubig(&value.unsigned_abs());
+        if value.sign() == Sign::Negative {
+            Err(ToUintError::ValueNegative(BITS, n))
+        } else if overflow {
+            Err(ToUintError::ValueTooLarge(BITS, n))
+        } else {
+            Ok(n)
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for IBig {
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for IBig {
+    fn from(value: &Uint<BITS, LIMBS>) -> Self {
+        UBig::from(value).into()
+    }
+}
+
+/// Converts through the little-endian bytes, which do not depend on the word
+/// size of the platform.
+fn from_ubig<const BITS: usize, const LIMBS: usize>(value: &UBig) -> (Uint<BITS, LIMBS>, bool) {
+    let limbs = value
+        .to_le_bytes()
+        .chunks(8)
+        .map(|chunk| {
+            let mut limb = [0; 8];
+            limb[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(limb)
+        })
+        .collect::<Vec<_>>();
+    Uint::overflowing_from_limbs_slice(&limbs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip_ubig() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let big: UBig = value.into();
+                assert_eq!(big.to_string(), value.to_string());
+                let back: U = big.try_into().unwrap();
+                assert_eq!(back, value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_roundtrip_ibig() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let big: IBig = value.into();
+                let back: U = big.try_into().unwrap();
+                assert_eq!(back, value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_errors() {
+        type U = Uint<64, 1>;
+        let big = UBig::from(u64::MAX) + UBig::from(2_u8);
+        assert_eq!(
+            U::try_from(&big),
+            Err(ToUintError::ValueTooLarge(64, U::from(1)))
+        );
+        let negative = IBig::from(-5);
+        assert_eq!(
+            U::try_from(negative),
+            Err(ToUintError::ValueNegative(64, U::from(5)))
+        );
+    }
+}
@@ -0,0 +1,402 @@
+//! Conversions between [`Uint`] amounts and decimal numbers at a fixed scale.
+//!
+//! A `Uint` amount `n` at scale `s` represents the decimal `n / 10^s`, like a
+//! token amount with `s` decimals. The conversions work on the decimal digits
+//! of the mantissa of the decimal and never go through floating point.
+#![cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+#![cfg_attr(
+    has_doc_cfg,
+    doc(cfg(any(feature = "rust_decimal", feature = "bigdecimal")))
+)]
+
+use crate::{BaseConvertError, Uint};
+use core::cmp::Ordering;
+use thiserror::Error;
+
+/// Error for [`from_decimal`][Uint::from_decimal] and
+/// [`to_decimal`][Uint::to_decimal].
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum DecimalError {
+    /// The decimal is negative.
+    #[error("The decimal is negative")]
+    Negative,
+
+    /// The decimal has a fractional remainder at the requested scale.
+    #[error("The decimal has a fractional remainder at the requested scale")]
+    Inexact,
+
+    /// The value is too large to fit the target type.
+    #[error("The value is too large to fit the target type")]
+    Overflow,
+
+    /// The scale `.0` is larger than the decimal type supports.
+    #[error("The scale {0} is larger than the decimal type supports")]
+    ScaleTooLarge(u32),
+}
+
+/// How [`from_decimal_rounded`][Uint::from_decimal_rounded] handles a
+/// fractional remainder at the requested scale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Return [`DecimalError::Inexact`].
+    Exact,
+
+    /// Round towards zero.
+    Down,
+
+    /// Round away from zero.
+    Up,
+
+    /// Round to the nearest value, with halfway cases away from zero.
+    HalfUp,
+
+    /// Round to the nearest value, with halfway cases to the even value.
+    HalfEven,
+}
+
+/// Decimal types that [`Uint`] converts to and from.
+///
+/// This is implemented for `rust_decimal::Decimal` and
+/// `bigdecimal::BigDecimal` and can not be implemented outside this crate.
+pub trait DecimalValue: Sized + private::Sealed {
+    /// Returns the sign, the big-endian decimal digits of the mantissa and
+    /// the number of digits after the decimal point.
+    #[doc(hidden)]
+    fn to_decimal_parts(&self) -> (bool, Vec<u8>, i64);
+
+    /// Constructs the non-negative decimal from the big-endian decimal digits
+    /// of the mantissa and the number of digits after the decimal point.
+    #[doc(hidden)]
+    fn from_decimal_parts(digits: Vec<u8>, scale: u32) -> Result<Self, DecimalError>;
+}
+
+pub mod private {
+    pub trait Sealed {}
+}
+
+impl From<BaseConvertError> for DecimalError {
+    fn from(_: BaseConvertError) -> Self {
+        Self::Overflow
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Converts a decimal to an amount at `scale` decimals, that is, returns
+    /// `value * 10^scale`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// use rust_decimal::Decimal;
+    /// let value: Decimal = "1.5".parse().unwrap();
+    /// assert_eq!(
+    ///     U256::from_decimal(&value, 18),
+    ///     Ok(U256::from(1_500_000_000_000_000_000_u64))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * [`DecimalError::Negative`] if the decimal is less than zero.
+    /// * [`DecimalError::Inexact`] if the decimal has more than `scale`
+    ///   significant digits after the decimal point.
+    /// * [`DecimalError::Overflow`] if the amount does not fit.
+    pub fn from_decimal<D: DecimalValue>(value: &D, scale: u32) -> Result<Self, DecimalError> {
+        Self::from_decimal_rounded(value, scale, Rounding::Exact)
+    }
+
+    /// Converts a decimal to an amount at `scale` decimals, rounding the
+    /// fractional remainder as specified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{aliases::U256, Rounding};
+    /// use rust_decimal::Decimal;
+    /// let value: Decimal = "2.345".parse().unwrap();
+    /// assert_eq!(
+    ///     U256::from_decimal_rounded(&value, 2, Rounding::Down),
+    ///     Ok(U256::from(234))
+    /// );
+    /// assert_eq!(
+    ///     U256::from_decimal_rounded(&value, 2, Rounding::HalfUp),
+    ///     Ok(U256::from(235))
+    /// );
+    /// assert_eq!(
+    ///     U256::from_decimal_rounded(&value, 2, Rounding::HalfEven),
+    ///     Ok(U256::from(234))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * [`DecimalError::Negative`] if the decimal is less than zero.
+    /// * [`DecimalError::Inexact`] if there is a fractional remainder and
+    ///   `rounding` is [`Rounding::Exact`].
+    /// * [`DecimalError::Overflow`] if the amount does not fit.
+    pub fn from_decimal_rounded<D: DecimalValue>(
+        value: &D,
+        scale: u32,
+        rounding: Rounding,
+    ) -> Result<Self, DecimalError> {
+        let (negative, mut digits, exponent) = value.to_decimal_parts();
+        if digits.iter().all(|&digit| digit == 0) {
+            return Ok(Self::ZERO);
+        }
+        if negative {
+            return Err(DecimalError::Negative);
+        }
+        let shift = i128::from(scale) - i128::from(exponent);
+        if shift == 0 {
+            return Ok(Self::from_base_be(10, digits.into_iter().map(u64::from))?);
+        }
+        if shift > 0 {
+            let factor = usize::try_from(shift)
+                .ok()
+                .and_then(|shift| Self::try_from(10_u64).ok()?.checked_pow(shift))
+                .ok_or(DecimalError::Overflow)?;
+            let mantissa = Self::from_base_be(10, digits.into_iter().map(u64::from))?;
+            return mantissa.checked_mul(factor).ok_or(DecimalError::Overflow);
+        }
+
+        // Split the digits into the integer part and the fraction.
+        let fraction_len = shift.unsigned_abs();
+        let split = usize::try_from(fraction_len)
+            .map_or(0, |fraction_len| digits.len().saturating_sub(fraction_len));
+        let fraction = digits.split_off(split);
+        let result = Self::from_base_be(10, digits.into_iter().map(u64::from))?;
+        if fraction.iter().all(|&digit| digit == 0) {
+            return Ok(result);
+        }
+
+        // Compare the fraction to one half.
+        let half = if fraction_len > fraction.len() as u128 {
+            Ordering::Less
+        } else {
+            match fraction[0].cmp(&5) {
+                Ordering::Equal if fraction[1..].iter().any(|&digit| digit != 0) => {
+                    Ordering::Greater
+                }
+                ordering => ordering,
+            }
+        };
+        let round_up = match rounding {
+            Rounding::Exact => return Err(DecimalError::Inexact),
+            Rounding::Down => false,
+            Rounding::Up => true,
+            Rounding::HalfUp => half != Ordering::Less,
+            Rounding::HalfEven => match half {
+                Ordering::Less => false,
+                Ordering::Equal => result.bit(0),
+                Ordering::Greater => true,
+            },
+        };
+        if round_up {
+            Self::try_from(1_u64)
+                .ok()
+                .and_then(|one| result.checked_add(one))
+                .ok_or(DecimalError::Overflow)
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// Converts an amount at `scale` decimals to a decimal, that is, returns
+    /// `self / 10^scale`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// use rust_decimal::Decimal;
+    /// let amount = U256::from(1_500_000_000_000_000_000_u64);
+    /// assert_eq!(amount.to_decimal::<Decimal>(18), Ok("1.5".parse().unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * [`DecimalError::Overflow`] if the mantissa does not fit the decimal
+    ///   type.
+    /// * [`DecimalError::ScaleTooLarge`] if the value can not be represented
+    ///   with the number of digits after the decimal point the decimal type
+    ///   supports.
+    pub fn to_decimal<D: DecimalValue>(&self, scale: u32) -> Result<D, DecimalError> {
+        #[allow(clippy::cast_possible_truncation)] // Digits are less than 10
+        let digits = self.to_base_be(10).map(|digit| digit as u8).collect();
+        D::from_decimal_parts(digits, scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use proptest::proptest;
+
+    /// A decimal given as sign, mantissa digits and exponent, to test the
+    /// digit logic independently of the decimal crates.
+    struct Parts(bool, Vec<u8>, i64);
+
+    impl private::Sealed for Parts {}
+
+    impl DecimalValue for Parts {
+        fn to_decimal_parts(&self) -> (bool, Vec<u8>, i64) {
+            (self.0, self.1.clone(), self.2)
+        }
+
+        fn from_decimal_parts(digits: Vec<u8>, scale: u32) -> Result<Self, DecimalError> {
+            Ok(Self(false, digits, i64::from(scale)))
+        }
+    }
+
+    fn parts(digits: &str, exponent: i64) -> Parts {
+        Parts(false, digits.bytes().map(|b| b - b'0').collect(), exponent)
+    }
+
+    #[test]
+    fn test_rounding() {
+        let cases = [
+            ("2000", 3, [Ok(2), Ok(2), Ok(2), Ok(2), Ok(2)]),
+            ("2340", 3, [
+                Err(DecimalError::Inexact),
+                Ok(2),
+                Ok(3),
+                Ok(2),
+                Ok(2),
+            ]),
+            ("2500", 3, [
+                Err(DecimalError::Inexact),
+                Ok(2),
+                Ok(3),
+                Ok(3),
+                Ok(2),
+            ]),
+            ("3500", 3, [
+                Err(DecimalError::Inexact),
+                Ok(3),
+                Ok(4),
+                Ok(4),
+                Ok(4),
+            ]),
+            ("2501", 3, [
+                Err(DecimalError::Inexact),
+                Ok(2),
+                Ok(3),
+                Ok(3),
+                Ok(3),
+            ]),
+            ("2499", 3, [
+                Err(DecimalError::Inexact),
+                Ok(2),
+                Ok(3),
+                Ok(2),
+                Ok(2),
+            ]),
+            ("5", 1, [
+                Err(DecimalError::Inexact),
+                Ok(0),
+                Ok(1),
+                Ok(1),
+                Ok(0),
+            ]),
+            ("5", 2, [
+                Err(DecimalError::Inexact),
+                Ok(0),
+                Ok(1),
+                Ok(0),
+                Ok(0),
+            ]),
+            ("9", 40, [
+                Err(DecimalError::Inexact),
+                Ok(0),
+                Ok(1),
+                Ok(0),
+                Ok(0),
+            ]),
+            ("9", i64::MAX, [
+                Err(DecimalError::Inexact),
+                Ok(0),
+                Ok(1),
+                Ok(0),
+                Ok(0),
+            ]),
+        ];
+        let roundings = [
+            Rounding::Exact,
+            Rounding::Down,
+            Rounding::Up,
+            Rounding::HalfUp,
+            Rounding::HalfEven,
+        ];
+        for (digits, exponent, expected) in cases {
+            for (rounding, expected) in roundings.into_iter().zip(expected) {
+                let value = parts(digits, exponent);
+                assert_eq!(
+                    U256::from_decimal_rounded(&value, 0, rounding),
+                    expected.map(U256::from),
+                    "{digits}e-{exponent} {rounding:?}"
+                );
+            }
+        }
+        // Trailing zeros of the fraction are not a remainder.
+        assert_eq!(
+            U256::from_decimal(&parts("2340", 3), 2),
+            Ok(U256::from(234))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            U256::from_decimal(&Parts(true, vec![1], 0), 0),
+            Err(DecimalError::Negative)
+        );
+        assert_eq!(
+            U256::from_decimal(&Parts(true, vec![0], 0), 0),
+            Ok(U256::ZERO)
+        );
+        assert_eq!(
+            U256::from_decimal(&parts("1", -2), 77),
+            Err(DecimalError::Overflow)
+        );
+        assert_eq!(
+            U256::from_decimal(&parts("1", -2), 75),
+            Ok(U256::from(10).pow(77))
+        );
+        assert_eq!(
+            U256::from_decimal(&parts("1", i64::MIN), 0),
+            Err(DecimalError::Overflow)
+        );
+        assert_eq!(U256::from_decimal(&parts("0", i64::MIN), 0), Ok(U256::ZERO));
+        assert_eq!(
+            Uint::<8, 1>::from_decimal(&parts("2555", 1), 0),
+            Err(DecimalError::Inexact)
+        );
+        assert_eq!(
+            Uint::<8, 1>::from_decimal_rounded(&parts("2555", 1), 0, Rounding::Up),
+            Err(DecimalError::Overflow)
+        );
+        assert_eq!(
+            Uint::<0, 0>::from_decimal_rounded(&parts("5", 1), 0, Rounding::Up),
+            Err(DecimalError::Overflow)
+        );
+        assert_eq!(
+            Uint::<0, 0>::from_decimal(&parts("1", 0), 0),
+            Err(DecimalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, scale in 0_u32..100)| {
+                let decimal: Parts = value.to_decimal(scale).unwrap();
+                assert_eq!(U::from_decimal(&decimal, scale), Ok(value));
+            });
+        });
+    }
+}
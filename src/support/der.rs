@@ -0,0 +1,70 @@
+//! Support for the [`der`](https://crates.io/crates/der) crate.
+//!
+//! Implements [`Encode`](der::Encode) and [`Decode`](der::Decode) through
+//! [`EncodeValue`], [`DecodeValue`] and [`FixedTag`] with tag `INTEGER`. The
+//! encoding is the same as [`Uint::to_der_integer`].
+#![cfg(feature = "der")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "der")))]
+
+use crate::Uint;
+use der::{DecodeValue, EncodeValue, FixedTag, Header, Length, Reader, Result, Tag, Writer};
+
+impl<const BITS: usize, const LIMBS: usize> FixedTag for Uint<BITS, LIMBS> {
+    const TAG: Tag = Tag::Integer;
+}
+
+impl<const BITS: usize, const LIMBS: usize> EncodeValue for Uint<BITS, LIMBS> {
+    fn value_len(&self) -> Result<Length> {
+        // One extra bit for the sign.
+        Length::try_from(self.bit_len() / 8 + 1)
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        writer.write(&self.der_integer_content())
+    }
+}
+
+impl<'a, const BITS: usize, const LIMBS: usize> DecodeValue<'a> for Uint<BITS, LIMBS> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        let content = reader.read_slice(header.length)?;
+        Self::from_der_integer_content(content).map_err(|_| Tag::Integer.value_error())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use der::{asn1::UintRef, Decode, Encode};
+    use hex_literal::hex;
+    use proptest::proptest;
+
+    #[test]
+    fn test_der() {
+        assert_eq!(U256::from(0).to_der().unwrap(), hex!("020100"));
+        assert_eq!(U256::from(0x80).to_der().unwrap(), hex!("02020080"));
+        assert_eq!(U256::from_der(&hex!("02020080")), Ok(U256::from(0x80)));
+        assert!(U256::from_der(&hex!("020180")).is_err());
+        assert!(U256::from_der(&hex!("0202007f")).is_err());
+        assert!(Uint::<8, 1>::from_der(&hex!("02020100")).is_err());
+    }
+
+    #[test]
+    fn test_der_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let encoded = value.to_der().unwrap();
+                assert_eq!(encoded, value.to_der_integer());
+                assert_eq!(encoded.len(), usize::try_from(value.encoded_len().unwrap()).unwrap());
+                assert_eq!(U::from_der(&encoded), Ok(value));
+
+                // Compatible with the `der` unsigned integer type.
+                let bytes = [&[0], value.to_be_bytes_vec().as_slice()].concat();
+                let expected = UintRef::new(&bytes).unwrap().to_der().unwrap();
+                assert_eq!(encoded, expected);
+            });
+        });
+    }
+}
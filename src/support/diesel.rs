@@ -0,0 +1,415 @@
+//! Support for the [`diesel`](https://crates.io/crates/diesel) crate.
+//!
+//! Implements [`ToSql`] and [`FromSql`] for the Postgres, MySQL and SQLite
+//! backends and the SQL types
+//!
+//! * `Numeric` as a decimal number. Postgres uses the same binary `NUMERIC`
+//!   encoding as the `postgres` support. Diesel binds `Numeric` as a `REAL` for
+//!   SQLite, so only values that a `f64` represents exactly can be written
+//!   there. Reading also accepts `INTEGER` and decimal text.
+//! * `Binary` as big-endian bytes.
+//! * `Text` as `0x`-prefixed big-endian hex strings, zero padded to the full
+//!   width so they sort like the numbers. Decimal strings are also accepted.
+//!
+//! Note: [`Uint`]s are never null, use [`Option<Uint>`] instead.
+#![cfg(feature = "diesel")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "diesel")))]
+
+use super::pg_numeric::{from_numeric, to_numeric};
+use crate::Uint;
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql, Queryable},
+    expression::AsExpression,
+    internal::derives::as_expression::Bound,
+    mysql::{Mysql, MysqlType, MysqlValue},
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::{Binary, Nullable, Numeric, SingleValue, Text},
+    sqlite::{Sqlite, SqliteType, SqliteValue},
+};
+use std::{
+    io::Write,
+    str::{from_utf8, FromStr},
+};
+use thiserror::Error;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Error)]
+pub enum ToSqlError {
+    #[error("The value can not be represented exactly as a SQLite REAL")]
+    Inexact,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Error)]
+pub enum FromSqlError {
+    #[error("The value is too large for the Uint type")]
+    Overflow,
+
+    #[error("The value is not an integer")]
+    NotAnInteger,
+
+    #[error("Unexpected value of database type {0}")]
+    UnexpectedType(String),
+}
+
+// Equivalent to `#[derive(AsExpression)]` with `#[diesel(sql_type = ...)]`.
+macro_rules! impl_as_expression {
+    ($($sql_type:ty),*) => {$(
+        impl<const BITS: usize, const LIMBS: usize> AsExpression<$sql_type> for Uint<BITS, LIMBS> {
+            type Expression = Bound<$sql_type, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> AsExpression<Nullable<$sql_type>>
+            for Uint<BITS, LIMBS>
+        {
+            type Expression = Bound<Nullable<$sql_type>, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+
+        impl<'a, const BITS: usize, const LIMBS: usize> AsExpression<$sql_type>
+            for &'a Uint<BITS, LIMBS>
+        {
+            type Expression = Bound<$sql_type, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+
+        impl<'a, const BITS: usize, const LIMBS: usize> AsExpression<Nullable<$sql_type>>
+            for &'a Uint<BITS, LIMBS>
+        {
+            type Expression = Bound<Nullable<$sql_type>, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+
+        impl<DB: Backend, const BITS: usize, const LIMBS: usize> ToSql<Nullable<$sql_type>, DB>
+            for Uint<BITS, LIMBS>
+        where
+            Self: ToSql<$sql_type, DB>,
+        {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+                ToSql::<$sql_type, DB>::to_sql(self, out)
+            }
+        }
+    )*};
+}
+
+impl_as_expression!(Numeric, Binary, Text);
+
+// Equivalent to `#[derive(FromSqlRow)]`.
+impl<DB, ST, const BITS: usize, const LIMBS: usize> Queryable<ST, DB> for Uint<BITS, LIMBS>
+where
+    DB: Backend,
+    ST: SingleValue,
+    Self: FromSql<ST, DB>,
+{
+    type Row = Self;
+
+    fn build(row: Self) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    fn to_hex_string(self) -> String {
+        let width = 2 + 2 * Self::BYTES;
+        format!("{self:#0width$x}")
+    }
+
+    fn from_be_slice_sql(bytes: &[u8]) -> deserialize::Result<Self> {
+        Ok(Self::try_from_be_slice(bytes).ok_or(FromSqlError::Overflow)?)
+    }
+
+    /// Parses a decimal number, allowing a fraction of zeros like
+    /// `NUMERIC(p, s)` columns return.
+    fn from_decimal_sql(text: &str) -> deserialize::Result<Self> {
+        let (integer, fraction) = text.split_once('.').unwrap_or((text, ""));
+        if !fraction.bytes().all(|byte| byte == b'0') {
+            return Err(Box::new(FromSqlError::NotAnInteger));
+        }
+        Ok(Self::from_str_radix(integer, 10)?)
+    }
+}
+
+// Postgres and MySQL take the serialized bytes.
+macro_rules! impl_bytes_backend {
+    ($($backend:ty, $value:ty;)*) => {$(
+        impl<const BITS: usize, const LIMBS: usize> ToSql<Binary, $backend> for Uint<BITS, LIMBS> {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, $backend>) -> serialize::Result {
+                out.write_all(&self.to_be_bytes_vec())?;
+                Ok(IsNull::No)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> FromSql<Binary, $backend> for Uint<BITS, LIMBS> {
+            fn from_sql(value: $value) -> deserialize::Result<Self> {
+                Self::from_be_slice_sql(value.as_bytes())
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> ToSql<Text, $backend> for Uint<BITS, LIMBS> {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, $backend>) -> serialize::Result {
+                out.write_all(self.to_hex_string().as_bytes())?;
+                Ok(IsNull::No)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> FromSql<Text, $backend> for Uint<BITS, LIMBS> {
+            fn from_sql(value: $value) -> deserialize::Result<Self> {
+                Ok(Self::from_str(from_utf8(value.as_bytes())?)?)
+            }
+        }
+    )*};
+}
+
+impl_bytes_backend!(
+    Pg, PgValue<'_>;
+    Mysql, MysqlValue<'_>;
+);
+
+impl<const BITS: usize, const LIMBS: usize> ToSql<Numeric, Pg> for Uint<BITS, LIMBS> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(&to_numeric(self)?)?;
+        Ok(IsNull::No)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromSql<Numeric, Pg> for Uint<BITS, LIMBS> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        Ok(from_numeric(value.as_bytes())?)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToSql<Numeric, Mysql> for Uint<BITS, LIMBS> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+        write!(out, "{self}")?;
+        Ok(IsNull::No)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromSql<Numeric, Mysql> for Uint<BITS, LIMBS> {
+    fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+        match value.value_type() {
+            MysqlType::Numeric => Self::from_decimal_sql(from_utf8(value.as_bytes())?),
+            ty => Err(Box::new(FromSqlError::UnexpectedType(format!("{ty:?}")))),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToSql<Binary, Sqlite> for Uint<BITS, LIMBS> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.to_be_bytes_vec());
+        Ok(IsNull::No)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromSql<Binary, Sqlite> for Uint<BITS, LIMBS> {
+    fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        Self::from_be_slice_sql(value.read_blob())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToSql<Text, Sqlite> for Uint<BITS, LIMBS> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.to_hex_string());
+        Ok(IsNull::No)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromSql<Text, Sqlite> for Uint<BITS, LIMBS> {
+    fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        Ok(Self::from_str(value.read_text())?)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToSql<Numeric, Sqlite> for Uint<BITS, LIMBS> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        // Diesel binds `Numeric` as `REAL` for SQLite.
+        let value = f64::from(self);
+        if Self::try_from(value).ok() != Some(*self) {
+            return Err(Box::new(ToSqlError::Inexact));
+        }
+        out.set_value(value);
+        Ok(IsNull::No)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromSql<Numeric, Sqlite> for Uint<BITS, LIMBS> {
+    fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        match value.value_type() {
+            Some(SqliteType::Long) => Ok(Self::try_from(value.read_long())?),
+            Some(SqliteType::Double) => {
+                let value = value.read_double();
+                if value.fract() != 0.0 {
+                    return Err(Box::new(FromSqlError::NotAnInteger));
+                }
+                Ok(Self::try_from(value)?)
+            }
+            Some(SqliteType::Text) => Self::from_decimal_sql(value.read_text()),
+            ty => Err(Box::new(FromSqlError::UnexpectedType(format!("{ty:?}")))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use diesel::{
+        connection::SimpleConnection, insert_into, prelude::*, select, sql_query,
+        sqlite::SqliteConnection,
+    };
+    use proptest::proptest;
+    use std::cell::{Cell, RefCell};
+
+    diesel::table! {
+        amounts (id) {
+            id -> Integer,
+            numeric -> Numeric,
+            binary -> Binary,
+            text -> Text,
+            nullable -> Nullable<Binary>,
+        }
+    }
+
+    fn connection() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        connection
+            .batch_execute(
+                "CREATE TABLE amounts (
+                    id INTEGER PRIMARY KEY,
+                    numeric NUMERIC NOT NULL,
+                    binary BLOB NOT NULL,
+                    text TEXT NOT NULL,
+                    nullable BLOB
+                )",
+            )
+            .unwrap();
+        connection
+    }
+
+    #[test]
+    fn test_sqlite_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let connection = RefCell::new(connection());
+            let id = Cell::new(0);
+            proptest!(|(value: U, small: u64, nullable: Option<U>)| {
+                id.set(id.get() + 1);
+                let id = id.get();
+                let small = U::saturating_from(small >> 11);
+                insert_into(amounts::table)
+                    .values((
+                        amounts::id.eq(id),
+                        amounts::numeric.eq(small),
+                        amounts::binary.eq(value),
+                        amounts::text.eq(&value),
+                        amounts::nullable.eq(nullable),
+                    ))
+                    .execute(&mut *connection.borrow_mut())
+                    .unwrap();
+                let row: (U, U, U, Option<U>) = amounts::table
+                    .select((amounts::numeric, amounts::binary, amounts::text, amounts::nullable))
+                    .filter(amounts::id.eq(id))
+                    .first(&mut *connection.borrow_mut())
+                    .unwrap();
+                assert_eq!(row, (small, value, value, nullable));
+            });
+        });
+    }
+
+    #[test]
+    fn test_sqlite_numeric() {
+        let mut connection = connection();
+        sql_query(
+            "INSERT INTO amounts VALUES (1, 1.0, x'', '0x0', NULL), (2, 1.5, x'', '42', NULL)",
+        )
+        .execute(&mut connection)
+        .unwrap();
+        let rows: Vec<(U256, U256)> = amounts::table
+            .select((amounts::binary, amounts::text))
+            .load(&mut connection)
+            .unwrap();
+        assert_eq!(rows, vec![
+            (U256::ZERO, U256::ZERO),
+            (U256::ZERO, U256::from(42))
+        ]);
+        let value: U256 = amounts::table
+            .select(amounts::numeric)
+            .filter(amounts::id.eq(1))
+            .first(&mut connection)
+            .unwrap();
+        assert_eq!(value, U256::from(1));
+        assert!(amounts::table
+            .select(amounts::numeric)
+            .filter(amounts::id.eq(2))
+            .first::<U256>(&mut connection)
+            .is_err());
+
+        // Decimal text is parsed where SQLite keeps it as text.
+        let value: U256 = select(diesel::dsl::sql::<Numeric>(
+            "'115792089237316195423570985008687907853269984665640564039457584007913129639935'",
+        ))
+        .get_result(&mut connection)
+        .unwrap();
+        assert_eq!(value, U256::MAX);
+        let value: U256 = select(diesel::dsl::sql::<Numeric>("'12.000'"))
+            .get_result(&mut connection)
+            .unwrap();
+        assert_eq!(value, U256::from(12));
+    }
+
+    #[test]
+    fn test_sqlite_errors() {
+        let mut connection = connection();
+        let value = U256::MAX;
+        insert_into(amounts::table)
+            .values((
+                amounts::id.eq(1),
+                amounts::numeric.eq(U256::ZERO),
+                amounts::binary.eq(value),
+                amounts::text.eq(value),
+            ))
+            .execute(&mut connection)
+            .unwrap();
+        assert!(amounts::table
+            .select(amounts::binary)
+            .first::<Uint<64, 1>>(&mut connection)
+            .is_err());
+        assert!(insert_into(amounts::table)
+            .values((
+                amounts::id.eq(2),
+                amounts::numeric.eq(U256::from(u64::MAX)),
+                amounts::binary.eq(value),
+                amounts::text.eq(value),
+            ))
+            .execute(&mut connection)
+            .is_err());
+        assert!(amounts::table
+            .select(amounts::text)
+            .first::<Uint<64, 1>>(&mut connection)
+            .is_err());
+    }
+
+    #[test]
+    fn test_from_decimal_sql() {
+        type U = Uint<64, 1>;
+        assert_eq!(U::from_decimal_sql("123").unwrap(), U::from(123));
+        assert_eq!(U::from_decimal_sql("123.000").unwrap(), U::from(123));
+        assert!(U::from_decimal_sql("123.001").is_err());
+        assert!(U::from_decimal_sql("-1").is_err());
+        assert!(U::from_decimal_sql("18446744073709551616").is_err());
+    }
+}
@@ -0,0 +1,82 @@
+//! Support for the [`ethnum`](https://crates.io/crates/ethnum) crate.
+#![cfg(feature = "ethnum")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "ethnum")))]
+
+use crate::{aliases as ours, FromUintError, ToUintError};
+use ethnum::{I256, U256};
+
+impl From<U256> for ours::U256 {
+    fn from(value: U256) -> Self {
+        Self::from_le_bytes(value.to_le_bytes())
+    }
+}
+
+impl From<ours::U256> for U256 {
+    fn from(value: ours::U256) -> Self {
+        Self::from_le_bytes(value.to_le_bytes())
+    }
+}
+
+impl TryFrom<I256> for ours::U256 {
+    type Error = ToUintError<Self>;
+
+    fn try_from(value: I256) -> Result<Self, Self::Error> {
+        let wrapped = Self::from_le_bytes(value.to_le_bytes());
+        if value < 0 {
+            Err(ToUintError::ValueNegative(256, wrapped))
+        } else {
+            Ok(wrapped)
+        }
+    }
+}
+
+impl TryFrom<ours::U256> for I256 {
+    type Error = FromUintError<Self>;
+
+    fn try_from(value: ours::U256) -> Result<Self, Self::Error> {
+        let wrapped = Self::from_le_bytes(value.to_le_bytes());
+        if wrapped < 0 {
+            Err(FromUintError::Overflow(256, wrapped, Self::MAX))
+        } else {
+            Ok(wrapped)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        proptest!(|(value: ours::U256)| {
+            let theirs: U256 = value.into();
+            assert_eq!(theirs.to_be_bytes(), value.to_be_bytes::<32>());
+            let ours: ours::U256 = theirs.into();
+            assert_eq!(ours, value);
+        });
+    }
+
+    #[test]
+    fn test_signed() {
+        proptest!(|(value: ours::U256)| {
+            match I256::try_from(value) {
+                Ok(signed) => {
+                    assert!(!value.bit(255));
+                    assert_eq!(ours::U256::try_from(signed), Ok(value));
+                }
+                Err(FromUintError::Overflow(256, wrapped, I256::MAX)) => {
+                    assert!(value.bit(255));
+                    assert_eq!(ours::U256::try_from(wrapped), Err(ToUintError::ValueNegative(256, value)));
+                }
+                Err(error) => panic!("unexpected {error:?}"),
+            }
+        });
+        assert_eq!(
+            ours::U256::try_from(I256::MINUS_ONE),
+            Err(ToUintError::ValueNegative(256, ours::U256::MAX))
+        );
+        assert_eq!(I256::try_from(ours::U256::MAX >> 1_usize), Ok(I256::MAX));
+    }
+}
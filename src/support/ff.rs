@@ -0,0 +1,151 @@
+//! Support for the [`ff`](https://crates.io/crates/ff) crate.
+//!
+//! The byte order of [`PrimeField::Repr`] is not specified by `ff`. It is
+//! detected from the representation of one, so both little-endian fields like
+//! those of `bls12_381` and `pasta_curves` and big-endian fields work.
+#![cfg(feature = "ff")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "ff")))]
+
+use crate::{ToUintError, Uint};
+use ff::PrimeField;
+
+/// Whether the representation of `F` is little-endian.
+fn is_little_endian<F: PrimeField>() -> bool {
+    F::ONE.to_repr().as_ref().first() == Some(&1)
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Converts an element of any `ff` [`PrimeField`] to its canonical value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// use bls12_381::Scalar;
+    /// let value = U256::from_prime_field(&Scalar::from(42)).unwrap();
+    /// assert_eq!(value, U256::from(42));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToUintError::ValueTooLarge`] if the value does not fit.
+    pub fn from_prime_field<F: PrimeField>(field: &F) -> Result<Self, ToUintError<Self>> {
+        let mut bytes = field.to_repr().as_ref().to_vec();
+        if !is_little_endian::<F>() {
+            bytes.reverse();
+        }
+        let limbs = bytes
+            .chunks(8)
+            .map(|chunk| {
+                let mut limb = [0; 8];
+                limb[..chunk.len()].copy_from_slice(chunk);
+                u64::from_le_bytes(limb)
+            })
+            .collect::<Vec<_>>();
+        match Self::overflowing_from_limbs_slice(&limbs) {
+            (n, false) => Ok(n),
+            (n, true) => Err(ToUintError::ValueTooLarge(BITS, n)),
+        }
+    }
+
+    /// Converts the value to an element of any `ff` [`PrimeField`].
+    ///
+    /// Returns [`None`] if the value is not less than the modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// use bls12_381::Scalar;
+    /// assert_eq!(U256::from(42).to_prime_field(), Some(Scalar::from(42)));
+    /// assert_eq!(U256::MAX.to_prime_field::<Scalar>(), None);
+    /// ```
+    #[must_use]
+    pub fn to_prime_field<F: PrimeField>(&self) -> Option<F> {
+        let mut repr = F::Repr::default();
+        let bytes = repr.as_mut();
+        let le_bytes = self.as_le_bytes();
+        let (low, high) = le_bytes.split_at(le_bytes.len().min(bytes.len()));
+        if high.iter().any(|&byte| byte != 0) {
+            return None;
+        }
+        bytes[..low.len()].copy_from_slice(low);
+        if !is_little_endian::<F>() {
+            bytes.reverse();
+        }
+        F::from_repr(repr).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use ff::Field;
+    use proptest::proptest;
+
+    fn modulus<F: PrimeField>() -> U256 {
+        U256::from_str_radix(F::MODULUS.trim_start_matches("0x"), 16).unwrap()
+    }
+
+    fn test_field<F: PrimeField>() {
+        let modulus = modulus::<F>();
+        proptest!(|(value: U256)| {
+            let value = value % modulus;
+            let field: F = value.to_prime_field().unwrap();
+            assert_eq!(U256::from_prime_field(&field), Ok(value));
+        });
+        assert_eq!(U256::from(1).to_prime_field(), Some(F::ONE));
+        assert_eq!(
+            U256::from_prime_field(&-F::ONE),
+            Ok(modulus - U256::from(1))
+        );
+        assert_eq!(modulus.to_prime_field::<F>(), None);
+        assert_eq!(
+            (Uint::<512, 8>::from(1) << 256_usize).to_prime_field::<F>(),
+            None
+        );
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                match value.to_prime_field::<F>() {
+                    Some(field) => assert_eq!(U::from_prime_field(&field), Ok(value)),
+                    None => assert!(value.bit_len() > 256 || U256::from(value) >= modulus),
+                }
+            });
+        });
+        assert!(matches!(
+            Uint::<64, 1>::from_prime_field(&-F::ONE),
+            Err(ToUintError::ValueTooLarge(64, _))
+        ));
+    }
+
+    #[test]
+    fn test_bls12_381() {
+        test_field::<bls12_381::Scalar>();
+    }
+
+    #[test]
+    fn test_pasta() {
+        test_field::<pasta_curves::Fp>();
+        test_field::<pasta_curves::Fq>();
+    }
+
+    // The BLS12-381 scalar field with a big-endian representation.
+    #[allow(clippy::expl_impl_clone_on_copy)]
+    mod big_endian {
+        #[derive(ff::PrimeField)]
+        #[PrimeFieldModulus = "52435875175126190479447740508185965837690552500527637822603658699938581184513"]
+        #[PrimeFieldGenerator = "7"]
+        #[PrimeFieldReprEndianness = "big"]
+        pub struct BigEndian([u64; 4]);
+    }
+    use big_endian::BigEndian;
+
+    #[test]
+    fn test_big_endian() {
+        assert_eq!(BigEndian::ONE.to_repr().as_ref()[31], 1);
+        test_field::<BigEndian>();
+    }
+}
@@ -1,34 +1,41 @@
 mod arbitrary;
-mod ark_ff;
+pub mod ark_ff;
+mod bigdecimal;
+mod bnum;
+mod bytemuck;
+pub mod ciborium;
+mod crypto_bigint;
+mod dashu;
+pub mod decimal;
+mod der;
+mod diesel;
+mod ethnum;
 mod fastrlp;
+mod ff;
 mod num_bigint;
+mod num_traits;
+mod pg_numeric;
 mod postgres;
 mod primitive_types;
 mod proptest;
+pub mod prost;
 mod pyo3;
 mod quickcheck;
 mod rand;
+pub mod redis;
+pub mod rkyv;
 mod rlp;
+mod rug;
+pub mod rusqlite;
+mod rust_decimal;
 mod serde;
-mod sqlx;
+pub mod sqlx;
+mod ssz;
 mod valuable;
+mod zerocopy;
 mod zeroize;
 
 // FEATURE: Support for many more traits and crates.
-// * https://crates.io/crates/num-traits
-// * https://crates.io/crates/der
 // * https://crates.io/crates/bitvec
 
-// * https://docs.rs/bytemuck/1.9.1/bytemuck/trait.Zeroable.html
-// * https://docs.rs/bytemuck/1.9.1/bytemuck/trait.Pod.html
-
-// Big int types:
-// * https://crates.io/crates/crypto-bigint
-// * https://crates.io/crates/rug
-// * https://crates.io/crates/bigdecimal
-// * https://crates.io/crates/rust_decimal
-//
-// More databases:
-// * https://crates.io/crates/diesel
-
 // FEATURE: Make sure `Bits` has the same level of support.
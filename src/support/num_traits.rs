@@ -0,0 +1,482 @@
+//! Support for the [`num-traits`](https://crates.io/crates/num-traits) and
+//! [`num-integer`](https://crates.io/crates/num-integer) crates.
+//!
+//! The trait methods delegate to the inherent methods of [`Uint`] and share
+//! their behaviour, except for `wrapping_shl` and `wrapping_shr`. Those shift
+//! by the amount modulo `BITS` like the primitive integers.
+//!
+//! `CheckedShl` and `CheckedShr` are not implemented. They require shifts by
+//! `u32`, and a second shift type next to `usize` would break type inference
+//! of shifts by literals like `x << 3`, which then default to `i32`. Use the
+//! inherent [`Uint::checked_shl`] and [`Uint::checked_shr`] instead.
+#![cfg(feature = "num-traits")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "num-traits")))]
+
+use crate::{string::ParseError, Uint};
+use num_integer::{Integer, Roots};
+use num_traits::{
+    ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub},
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, FromPrimitive,
+    Num, NumCast, One, Pow, PrimInt, Saturating, SaturatingAdd, SaturatingMul, SaturatingSub,
+    ToPrimitive, Unsigned, WrappingAdd, WrappingMul, WrappingNeg, WrappingShl, WrappingShr,
+    WrappingSub, Zero,
+};
+
+impl<const BITS: usize, const LIMBS: usize> Zero for Uint<BITS, LIMBS> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> One for Uint<BITS, LIMBS> {
+    fn one() -> Self {
+        Self::from(1)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Bounded for Uint<BITS, LIMBS> {
+    fn min_value() -> Self {
+        Self::ZERO
+    }
+
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Num for Uint<BITS, LIMBS> {
+    type FromStrRadixErr = ParseError;
+
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        Self::from_str_radix(src, radix.into())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Unsigned for Uint<BITS, LIMBS> {}
+
+macro_rules! impl_checked {
+    ($($trait:ident $fn:ident),*) => {$(
+        impl<const BITS: usize, const LIMBS: usize> $trait for Uint<BITS, LIMBS> {
+            fn $fn(&self, other: &Self) -> Option<Self> {
+                Self::$fn(*self, *other)
+            }
+        }
+    )*};
+}
+
+impl_checked!(
+    CheckedAdd checked_add,
+    CheckedSub checked_sub,
+    CheckedMul checked_mul,
+    CheckedDiv checked_div,
+    CheckedRem checked_rem
+);
+
+impl<const BITS: usize, const LIMBS: usize> CheckedNeg for Uint<BITS, LIMBS> {
+    fn checked_neg(&self) -> Option<Self> {
+        Self::checked_neg(*self)
+    }
+}
+
+macro_rules! impl_binary {
+    ($($trait:ident $fn:ident -> $output:ty),*) => {$(
+        impl<const BITS: usize, const LIMBS: usize> $trait for Uint<BITS, LIMBS> {
+            fn $fn(&self, other: &Self) -> $output {
+                Self::$fn(*self, *other)
+            }
+        }
+    )*};
+}
+
+impl_binary!(
+    WrappingAdd wrapping_add -> Self,
+    WrappingSub wrapping_sub -> Self,
+    WrappingMul wrapping_mul -> Self,
+    SaturatingAdd saturating_add -> Self,
+    SaturatingSub saturating_sub -> Self,
+    SaturatingMul saturating_mul -> Self,
+    OverflowingAdd overflowing_add -> (Self, bool),
+    OverflowingSub overflowing_sub -> (Self, bool),
+    OverflowingMul overflowing_mul -> (Self, bool)
+);
+
+impl<const BITS: usize, const LIMBS: usize> WrappingNeg for Uint<BITS, LIMBS> {
+    fn wrapping_neg(&self) -> Self {
+        Self::wrapping_neg(*self)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> WrappingShl for Uint<BITS, LIMBS> {
+    fn wrapping_shl(&self, rhs: u32) -> Self {
+        *self << wrap_shift::<BITS>(rhs)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> WrappingShr for Uint<BITS, LIMBS> {
+    fn wrapping_shr(&self, rhs: u32) -> Self {
+        *self >> wrap_shift::<BITS>(rhs)
+    }
+}
+
+/// Reduces a shift modulo `BITS`, like the primitive wrapping shifts mask it.
+const fn wrap_shift<const BITS: usize>(rhs: u32) -> usize {
+    if BITS == 0 {
+        0
+    } else {
+        rhs as usize % BITS
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Saturating for Uint<BITS, LIMBS> {
+    fn saturating_add(self, other: Self) -> Self {
+        Self::saturating_add(self, other)
+    }
+
+    fn saturating_sub(self, other: Self) -> Self {
+        Self::saturating_sub(self, other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Pow<usize> for Uint<BITS, LIMBS> {
+    type Output = Self;
+
+    fn pow(self, exp: usize) -> Self {
+        Self::pow(self, exp)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Pow<u32> for Uint<BITS, LIMBS> {
+    type Output = Self;
+
+    fn pow(self, exp: u32) -> Self {
+        Self::pow(self, exp as usize)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToPrimitive for Uint<BITS, LIMBS> {
+    fn to_i64(&self) -> Option<i64> {
+        self.try_into().ok()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.try_into().ok()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.try_into().ok()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.try_into().ok()
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        Some(self.into())
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.into())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromPrimitive for Uint<BITS, LIMBS> {
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    /// Truncates towards zero like the primitive integers do.
+    fn from_f64(n: f64) -> Option<Self> {
+        Self::try_from(n.trunc()).ok()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> NumCast for Uint<BITS, LIMBS> {
+    /// Converts values that fit a [`u128`].
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        Self::try_from(n.to_u128()?).ok()
+    }
+}
+
+// Bit counts are less than `2^32` for any practical `BITS`.
+#[allow(clippy::cast_possible_truncation)]
+impl<const BITS: usize, const LIMBS: usize> PrimInt for Uint<BITS, LIMBS> {
+    fn count_ones(self) -> u32 {
+        Self::count_ones(&self) as u32
+    }
+
+    fn count_zeros(self) -> u32 {
+        Self::count_zeros(&self) as u32
+    }
+
+    fn leading_zeros(self) -> u32 {
+        Self::leading_zeros(&self) as u32
+    }
+
+    fn trailing_zeros(self) -> u32 {
+        Self::trailing_zeros(&self) as u32
+    }
+
+    fn leading_ones(self) -> u32 {
+        Self::leading_ones(&self) as u32
+    }
+
+    fn trailing_ones(self) -> u32 {
+        Self::trailing_ones(&self) as u32
+    }
+
+    fn rotate_left(self, n: u32) -> Self {
+        Self::rotate_left(self, n as usize)
+    }
+
+    fn rotate_right(self, n: u32) -> Self {
+        Self::rotate_right(self, n as usize)
+    }
+
+    /// Shifts in copies of the most significant bit, as if the value was
+    /// signed.
+    fn signed_shr(self, n: u32) -> Self {
+        let n = n as usize;
+        let shifted = self >> n;
+        if BITS > 0 && self.leading_zeros() == 0 {
+            shifted | !(Self::MAX >> n)
+        } else {
+            shifted
+        }
+    }
+
+    fn signed_shl(self, n: u32) -> Self {
+        self << n as usize
+    }
+
+    fn unsigned_shl(self, n: u32) -> Self {
+        self << n as usize
+    }
+
+    fn unsigned_shr(self, n: u32) -> Self {
+        self >> n as usize
+    }
+
+    /// Reverses the [`Self::BYTES`] little-endian bytes. If `BITS` is not a
+    /// multiple of eight, the high bits that do not fit are discarded.
+    fn swap_bytes(self) -> Self {
+        let mut bytes = self.to_le_bytes_vec();
+        bytes.reverse();
+        let limbs = bytes
+            .chunks(8)
+            .map(|chunk| {
+                let mut limb = [0; 8];
+                limb[..chunk.len()].copy_from_slice(chunk);
+                u64::from_le_bytes(limb)
+            })
+            .collect::<Vec<_>>();
+        Self::wrapping_from_limbs_slice(&limbs)
+    }
+
+    fn reverse_bits(self) -> Self {
+        Self::reverse_bits(self)
+    }
+
+    fn from_be(value: Self) -> Self {
+        value.to_be()
+    }
+
+    fn from_le(value: Self) -> Self {
+        value.to_le()
+    }
+
+    fn to_be(self) -> Self {
+        if cfg!(target_endian = "big") {
+            self
+        } else {
+            self.swap_bytes()
+        }
+    }
+
+    fn to_le(self) -> Self {
+        if cfg!(target_endian = "little") {
+            self
+        } else {
+            self.swap_bytes()
+        }
+    }
+
+    fn pow(self, exp: u32) -> Self {
+        Self::pow(self, exp as usize)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Integer for Uint<BITS, LIMBS> {
+    fn div_floor(&self, other: &Self) -> Self {
+        *self / *other
+    }
+
+    fn mod_floor(&self, other: &Self) -> Self {
+        *self % *other
+    }
+
+    fn gcd(&self, other: &Self) -> Self {
+        Self::gcd(*self, *other)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    fn lcm(&self, other: &Self) -> Self {
+        Self::lcm(*self, *other).expect("least common multiple overflows")
+    }
+
+    fn divides(&self, other: &Self) -> bool {
+        self.is_multiple_of(other)
+    }
+
+    fn is_multiple_of(&self, other: &Self) -> bool {
+        if *other == Self::ZERO {
+            *self == Self::ZERO
+        } else {
+            *self % *other == Self::ZERO
+        }
+    }
+
+    fn is_even(&self) -> bool {
+        !self.bit(0)
+    }
+
+    fn is_odd(&self) -> bool {
+        self.bit(0)
+    }
+
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        Self::div_rem(*self, *other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Roots for Uint<BITS, LIMBS> {
+    fn nth_root(&self, n: u32) -> Self {
+        self.root(n as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U64, const_for, nbytes, nlimbs};
+    use proptest::proptest;
+
+    fn generic<T: PrimInt + Unsigned + Integer + Roots + Pow<u32, Output = T>>(a: T, b: T) -> T {
+        let sum = a.saturating_add(b);
+        let gcd = a.gcd(&b);
+        let root = sum.sqrt();
+        root.checked_add(&gcd).unwrap_or_else(T::max_value)
+    }
+
+    #[test]
+    fn test_generic() {
+        proptest!(|(a: u64, b: u64)| {
+            assert_eq!(generic(U64::from(a), U64::from(b)), U64::from(generic(a, b)));
+        });
+    }
+
+    #[test]
+    #[allow(
+        clippy::cast_possible_wrap,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn test_u64() {
+        proptest!(|(a: u64, b: u64, n in 0_u32..70)| {
+            let (x, y) = (U64::from(a), U64::from(b));
+            assert_eq!(CheckedAdd::checked_add(&x, &y), a.checked_add(b).map(U64::from));
+            assert_eq!(CheckedSub::checked_sub(&x, &y), a.checked_sub(b).map(U64::from));
+            assert_eq!(CheckedMul::checked_mul(&x, &y), a.checked_mul(b).map(U64::from));
+            assert_eq!(CheckedDiv::checked_div(&x, &y), a.checked_div(b).map(U64::from));
+            assert_eq!(CheckedRem::checked_rem(&x, &y), a.checked_rem(b).map(U64::from));
+            assert_eq!(WrappingMul::wrapping_mul(&x, &y), U64::from(a.wrapping_mul(b)));
+            assert_eq!(WrappingNeg::wrapping_neg(&x), U64::from(a.wrapping_neg()));
+            assert_eq!(SaturatingSub::saturating_sub(&x, &y), U64::from(a.saturating_sub(b)));
+            let (value, overflow) = OverflowingAdd::overflowing_add(&x, &y);
+            assert_eq!((value, overflow), (U64::from(a.overflowing_add(b).0), a.overflowing_add(b).1));
+            assert_eq!(PrimInt::count_ones(x), a.count_ones());
+            assert_eq!(PrimInt::leading_zeros(x), a.leading_zeros());
+            assert_eq!(PrimInt::trailing_ones(x), a.trailing_ones());
+            assert_eq!(PrimInt::swap_bytes(x), U64::from(a.swap_bytes()));
+            assert_eq!(PrimInt::to_be(x), U64::from(a.to_be()));
+            assert_eq!(PrimInt::rotate_left(x, n), U64::from(a.rotate_left(n)));
+            assert_eq!(PrimInt::pow(x, n % 4), U64::from(a.wrapping_pow(n % 4)));
+            if n < 64 {
+                assert_eq!(PrimInt::signed_shr(x, n), U64::from(((a as i64) >> n) as u64));
+            }
+            assert_eq!(WrappingShl::wrapping_shl(&x, n), U64::from(a.wrapping_shl(n)));
+            assert_eq!(WrappingShr::wrapping_shr(&x, n), U64::from(a.wrapping_shr(n)));
+            assert_eq!(Integer::div_floor(&x, &y.max(U64::from(1))), U64::from(a / b.max(1)));
+            assert_eq!(Integer::lcm(&U64::from(a >> 33), &U64::from(b >> 33)), U64::from(Integer::lcm(&(a >> 33), &(b >> 33))));
+            assert_eq!(Integer::is_multiple_of(&x, &y), Integer::is_multiple_of(&a, &b));
+            assert_eq!(Integer::is_even(&x), a % 2 == 0);
+            assert_eq!(Roots::cbrt(&x), U64::from(Roots::cbrt(&a)));
+            assert_eq!(x.to_u32(), u32::try_from(a).ok());
+            assert_eq!(x.to_f64(), Some(a as f64));
+            assert_eq!(<U64 as NumCast>::from(a), Some(x));
+        });
+    }
+
+    #[test]
+    fn test_primitive() {
+        type U = Uint<256, 4>;
+        assert_eq!(U::zero(), U::ZERO);
+        assert!(U::zero().is_zero());
+        assert_eq!(U::one(), U::from(1));
+        assert_eq!((U::min_value(), U::max_value()), (U::ZERO, U::MAX));
+        assert_eq!(<U as Num>::from_str_radix("ff", 16), Ok(U::from(255)));
+        assert!(<U as Num>::from_str_radix("fg", 16).is_err());
+        assert_eq!(U::from_f64(2.7), Some(U::from(2)));
+        assert_eq!(U::from_f64(-1.0), None);
+        assert_eq!(U::from_f64(f64::NAN), None);
+        assert_eq!(U::from_i64(-1), None);
+        assert_eq!(U::from_u128(u128::MAX), Some(U::from(u128::MAX)));
+        assert_eq!(U::MAX.to_u128(), None);
+        assert_eq!(<U as NumCast>::from(-1_i32), None);
+        assert_eq!(<U as NumCast>::from(1e3), Some(U::from(1000)));
+        assert_eq!(U::from(1).checked_shl(255), Some(U::from(1) << 255));
+        assert_eq!(WrappingShl::wrapping_shl(&U::from(1), 257), U::from(2));
+        assert_eq!(WrappingShr::wrapping_shr(&U::MAX, 511), U::from(1));
+        assert_eq!(
+            WrappingShl::wrapping_shl(&Uint::<0, 0>::ZERO, 1),
+            Uint::ZERO
+        );
+        assert_eq!(CheckedNeg::checked_neg(&U::from(1)), None);
+        assert_eq!(Pow::pow(U::from(2), 10_usize), U::from(1024));
+        assert_eq!(U::MAX.signed_shr(300), U::MAX);
+        assert_eq!(Uint::<0, 0>::ZERO.signed_shr(1), Uint::ZERO);
+    }
+
+    #[test]
+    fn test_swap_bytes() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                if nbytes(BITS) * 8 == BITS {
+                    assert_eq!(value.swap_bytes().swap_bytes(), value);
+                    assert_eq!(value.swap_bytes().to_le_bytes_vec(), value.to_be_bytes_vec());
+                    assert_eq!(U::from_be(value.to_be()), value);
+                }
+                assert_eq!(U::from_le(value.to_le()), value);
+            });
+        });
+    }
+}
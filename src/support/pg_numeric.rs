@@ -0,0 +1,92 @@
+//! The Postgres binary `NUMERIC` format, shared by the `postgres`, `diesel` and
+//! `sqlx` support.
+//!
+//! See <https://github.com/postgres/postgres/blob/05a5a1775c89f6beb326725282e7eea1373cbec8/src/backend/utils/adt/numeric.c#L253>
+#![cfg(any(feature = "postgres", feature = "diesel", feature = "sqlx"))]
+
+use crate::{utils::trim_end_vec, BaseConvertError, Uint};
+use std::{iter, num::TryFromIntError};
+use thiserror::Error;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Error)]
+pub enum NumericError {
+    #[error("Invalid NUMERIC encoding")]
+    Invalid,
+
+    #[error(transparent)]
+    BaseConvert(#[from] BaseConvertError),
+}
+
+/// Encodes the value in the binary `NUMERIC` format.
+pub fn to_numeric<const BITS: usize, const LIMBS: usize>(
+    value: &Uint<BITS, LIMBS>,
+) -> Result<Vec<u8>, TryFromIntError> {
+    // Everything is done in big-endian base 1000 digits.
+    const BASE: u64 = 10000;
+    let mut digits: Vec<_> = value.to_base_be(BASE).collect();
+    let exponent: i16 = digits.len().saturating_sub(1).try_into()?;
+
+    // Trailing zeros are removed.
+    trim_end_vec(&mut digits, &0);
+
+    let mut out = Vec::with_capacity(8 + 2 * digits.len());
+    let len: i16 = digits.len().try_into()?;
+    out.extend_from_slice(&len.to_be_bytes()); // Number of digits.
+    out.extend_from_slice(&exponent.to_be_bytes()); // Exponent of first digit.
+    out.extend_from_slice(&0_i16.to_be_bytes()); // sign: 0x0000 = positive, 0x4000 = negative.
+    out.extend_from_slice(&0_i16.to_be_bytes()); // dscale: Number of digits to the right of the decimal point.
+    for digit in digits {
+        debug_assert!(digit < BASE);
+        #[allow(clippy::cast_possible_truncation)] // 10000 < i16::MAX
+        out.extend_from_slice(&(digit as i16).to_be_bytes());
+    }
+    Ok(out)
+}
+
+/// Decodes a value in the binary `NUMERIC` format.
+pub fn from_numeric<const BITS: usize, const LIMBS: usize>(
+    raw: &[u8],
+) -> Result<Uint<BITS, LIMBS>, NumericError> {
+    // Parse header
+    if raw.len() < 8 {
+        return Err(NumericError::Invalid);
+    }
+    let read = |i: usize| i16::from_be_bytes([raw[i], raw[i + 1]]);
+    let digits = read(0);
+    let exponent = read(2);
+    let sign = read(4);
+    let dscale = read(6);
+    let raw = &raw[8..];
+    #[allow(clippy::cast_sign_loss)] // Signs are checked
+    if digits < 0
+        || exponent < 0
+        || sign != 0x0000
+        || dscale != 0
+        || digits > exponent + 1
+        || raw.len() != digits as usize * 2
+    {
+        return Err(NumericError::Invalid);
+    }
+    let mut error = false;
+    let iter = raw.chunks_exact(2).filter_map(|raw| {
+        if error {
+            return None;
+        }
+        let digit = i16::from_be_bytes([raw[0], raw[1]]);
+        if !(0..10000).contains(&digit) {
+            error = true;
+            return None;
+        }
+        #[allow(clippy::cast_sign_loss)] // Signs are checked
+        Some(digit as u64)
+    });
+    #[allow(clippy::cast_sign_loss)]
+    // Expression can not be negative due to checks above
+    let iter = iter.chain(iter::repeat(0).take((exponent + 1 - digits) as usize));
+
+    let value = Uint::from_base_be(10000, iter)?;
+    if error {
+        return Err(NumericError::Invalid);
+    }
+    Ok(value)
+}
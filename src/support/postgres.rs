@@ -2,15 +2,12 @@
 #![cfg(feature = "postgres")]
 #![cfg_attr(has_doc_cfg, doc(cfg(feature = "postgres")))]
 
-use crate::{
-    utils::{rem_up, trim_end_vec},
-    Uint,
-};
+use super::pg_numeric::{from_numeric, to_numeric, NumericError};
+use crate::{utils::rem_up, Uint};
 use bytes::{BufMut, BytesMut};
 use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type, WrongType};
 use std::{
     error::Error,
-    iter,
     str::{from_utf8, FromStr},
 };
 use thiserror::Error;
@@ -128,39 +125,22 @@ impl<const BITS: usize, const LIMBS: usize> ToSql for Uint<BITS, LIMBS> {
                 }
             }
 
-            // Hex strings
+            // Hex strings, zero padded to the full width
             Type::CHAR | Type::TEXT | Type::VARCHAR => {
-                out.put_slice(format!("{:#x}", self).as_bytes());
+                let width = 2 + 2 * Self::BYTES;
+                out.put_slice(format!("{self:#0width$x}").as_bytes());
             }
             Type::JSON | Type::JSONB => {
                 if *ty == Type::JSONB {
                     // Version 1 of JSONB is just plain text JSON.
                     out.put_u8(1);
                 }
-                out.put_slice(format!("\"{:#x}\"", self).as_bytes());
+                let width = 2 + 2 * Self::BYTES;
+                out.put_slice(format!("\"{self:#0width$x}\"").as_bytes());
             }
 
             // Binary coded decimal types
-            // See <https://github.com/postgres/postgres/blob/05a5a1775c89f6beb326725282e7eea1373cbec8/src/backend/utils/adt/numeric.c#L253>
-            Type::NUMERIC => {
-                // Everything is done in big-endian base 1000 digits.
-                const BASE: u64 = 10000;
-                let mut digits: Vec<_> = self.to_base_be(BASE).collect();
-                let exponent = digits.len().saturating_sub(1).try_into()?;
-
-                // Trailing zeros are removed.
-                trim_end_vec(&mut digits, &0);
-
-                out.put_i16(digits.len().try_into()?); // Number of digits.
-                out.put_i16(exponent); // Exponent of first digit.
-                out.put_i16(0); // sign: 0x0000 = positive, 0x4000 = negative.
-                out.put_i16(0); // dscale: Number of digits to the right of the decimal point.
-                for digit in digits {
-                    debug_assert!(digit < BASE);
-                    #[allow(clippy::cast_possible_truncation)] // 10000 < i16::MAX
-                    out.put_i16(digit as i16);
-                }
-            }
+            Type::NUMERIC => out.put_slice(&to_numeric(self)?),
 
             // Unsupported types
             _ => {
@@ -254,49 +234,12 @@ impl<'a, const BITS: usize, const LIMBS: usize> FromSql<'a> for Uint<BITS, LIMBS
             }
 
             // Numeric types
-            Type::NUMERIC => {
-                // Parse header
-                if raw.len() < 8 {
-                    return Err(Box::new(FromSqlError::ParseError(ty.clone())));
-                }
-                let digits = i16::from_be_bytes(raw[0..2].try_into()?);
-                let exponent = i16::from_be_bytes(raw[2..4].try_into()?);
-                let sign = i16::from_be_bytes(raw[4..6].try_into()?);
-                let dscale = i16::from_be_bytes(raw[6..8].try_into()?);
-                let raw = &raw[8..];
-                #[allow(clippy::cast_sign_loss)] // Signs are checked
-                if digits < 0
-                    || exponent < 0
-                    || sign != 0x0000
-                    || dscale != 0
-                    || digits > exponent + 1
-                    || raw.len() != digits as usize * 2
-                {
-                    return Err(Box::new(FromSqlError::ParseError(ty.clone())));
-                }
-                let mut error = false;
-                let iter = raw.chunks_exact(2).filter_map(|raw| {
-                    if error {
-                        return None;
-                    }
-                    let digit = i16::from_be_bytes(raw.try_into().unwrap());
-                    if !(0..10000).contains(&digit) {
-                        error = true;
-                        return None;
-                    }
-                    #[allow(clippy::cast_sign_loss)] // Signs are checked
-                    Some(digit as u64)
-                });
-                #[allow(clippy::cast_sign_loss)]
-                // Expression can not be negative due to checks above
-                let iter = iter.chain(iter::repeat(0).take((exponent + 1 - digits) as usize));
-
-                let value = Self::from_base_be(10000, iter)?;
-                if error {
-                    return Err(Box::new(FromSqlError::ParseError(ty.clone())));
+            Type::NUMERIC => from_numeric(raw).map_err(|error| -> BoxedError {
+                match error {
+                    NumericError::Invalid => Box::new(FromSqlError::ParseError(ty.clone())),
+                    NumericError::BaseConvert(error) => Box::new(error),
                 }
-                value
-            }
+            })?,
 
             // Unsupported types
             _ => return Err(Box::new(WrongType::new::<Self>(ty.clone()))),
@@ -307,7 +250,7 @@ impl<'a, const BITS: usize, const LIMBS: usize> FromSql<'a> for Uint<BITS, LIMBS
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{const_for, nbytes, nlimbs};
+    use crate::{const_for, nbytes, nlimbs, ParseError, ParseErrorKind};
     use approx::assert_ulps_eq;
     use hex_literal::hex;
     use postgres::{Client, NoTls};
@@ -349,6 +292,24 @@ mod tests {
         assert_eq!(bytes(Type::VARCHAR), hex!("307863383565663764373936393166653739353733623161373036346331396331613938313965626462643166616161623161386563393233343434333861616634"));
     }
 
+    #[test]
+    fn test_text_too_long() {
+        type U = Uint<64, 1>;
+        let text = format!("0x{}ff", "0".repeat(1_000_000));
+        assert_eq!(
+            U::from_sql(&Type::TEXT, text.as_bytes()).unwrap(),
+            U::from(255)
+        );
+        let text = format!("0x1{}", "0".repeat(16));
+        for ty in [Type::TEXT, Type::JSON] {
+            let error = U::from_sql(&ty, text.as_bytes()).unwrap_err();
+            assert_eq!(
+                error.downcast_ref::<ParseError>().map(ParseError::kind),
+                Some(ParseErrorKind::InputTooLong(16))
+            );
+        }
+    }
+
     #[test]
     fn test_roundtrip() {
         const_for!(BITS in SIZES {
@@ -449,15 +410,26 @@ mod tests {
         // Fetch ground truth value from Postgres
         let expr = match *ty {
             Type::BIT => format!(
-                "B'{value:b}'::bit({bits})",
+                "B'{value:0bits$b}'::bit({bits})",
                 value = value,
                 bits = if BITS == 0 { 1 } else { BITS },
             ),
             Type::VARBIT => format!("B'{value:b}'::varbit", value = value,),
-            Type::BYTEA => format!("'\\x{:x}'::bytea", value),
-            Type::CHAR => format!("'{:#x}'::char({})", value, 2 + 2 * nbytes(BITS)),
-            Type::TEXT | Type::VARCHAR => format!("'{:#x}'::{}", value, ty.name()),
-            Type::JSON | Type::JSONB => format!("'\"{:#x}\"'::{}", value, ty.name()),
+            Type::BYTEA => format!("'\\x{value:0width$x}'::bytea", width = 2 * nbytes(BITS)),
+            Type::CHAR => format!(
+                "'{value:#0width$x}'::char({width})",
+                width = 2 + 2 * nbytes(BITS)
+            ),
+            Type::TEXT | Type::VARCHAR => format!(
+                "'{value:#0width$x}'::{}",
+                ty.name(),
+                width = 2 + 2 * nbytes(BITS)
+            ),
+            Type::JSON | Type::JSONB => format!(
+                "'\"{value:#0width$x}\"'::{}",
+                ty.name(),
+                width = 2 + 2 * nbytes(BITS)
+            ),
             _ => format!("{}::{}", value, ty.name()),
         };
         // dbg!(&expr);
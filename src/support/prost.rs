@@ -0,0 +1,218 @@
+//! Support for the [`prost`](https://crates.io/crates/prost) crate.
+//!
+//! Provides two protobuf messages that carry a [`Uint`] of any size. They can
+//! be embedded as message fields in `prost` generated or derived types and
+//! convert to and from [`Uint`] with [`From`] and [`TryFrom`].
+//!
+//! The equivalent `.proto` definitions are
+//!
+//! ```protobuf
+//! // Unsigned integer as big-endian bytes without leading zeros.
+//! message UintBytes {
+//!   bytes value = 1;
+//! }
+//!
+//! // Unsigned integer as a base 10 string.
+//! message UintDecimal {
+//!   string value = 1;
+//! }
+//! ```
+//!
+//! In both messages the default (empty) value is zero, following the proto3
+//! convention for missing fields.
+//!
+//! ```
+//! # use ruint::{aliases::U256, proto::UintBytes, uint};
+//! use prost::Message;
+//!
+//! #[derive(Clone, PartialEq, Message)]
+//! struct Transfer {
+//!     #[prost(message, optional, tag = "1")]
+//!     amount: Option<UintBytes>,
+//! }
+//!
+//! let transfer = Transfer {
+//!     amount: Some(uint!(0x1234_U256).into()),
+//! };
+//! let encoded = transfer.encode_to_vec();
+//! assert_eq!(encoded, [0x0a, 0x04, 0x0a, 0x02, 0x12, 0x34]);
+//!
+//! let decoded = Transfer::decode(&encoded[..]).unwrap();
+//! let amount = U256::try_from(decoded.amount.unwrap()).unwrap();
+//! assert_eq!(amount, uint!(0x1234_U256));
+//! ```
+#![cfg(feature = "prost")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "prost")))]
+
+use crate::{from::ToUintError, string::ParseError, Uint};
+use prost::Message;
+
+/// Protobuf message holding an unsigned integer as big-endian bytes.
+///
+/// Encoding produces the shortest representation, so zero is the empty byte
+/// string. Decoding accepts leading zeros.
+#[derive(Clone, PartialEq, Eq, Hash, Message)]
+pub struct UintBytes {
+    /// The big-endian value.
+    #[prost(bytes = "vec", tag = "1")]
+    pub value: Vec<u8>,
+}
+
+/// Protobuf message holding an unsigned integer as a decimal string.
+///
+/// Decoding follows [`Uint::from_str_radix`] with radix 10, so `_` separators
+/// are allowed and the empty string is zero.
+#[derive(Clone, PartialEq, Eq, Hash, Message)]
+pub struct UintDecimal {
+    /// The base 10 value.
+    #[prost(string, tag = "1")]
+    pub value: String,
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for UintBytes {
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for UintBytes {
+    fn from(value: &Uint<BITS, LIMBS>) -> Self {
+        Self {
+            value: value.to_be_bytes_trimmed_vec(),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<UintBytes> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    fn try_from(value: UintBytes) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&UintBytes> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    fn try_from(value: &UintBytes) -> Result<Self, Self::Error> {
+        let limbs: Vec<u64> = value
+            .value
+            .rchunks(8)
+            .map(|chunk| {
+                let mut limb = [0; 8];
+                limb[8 - chunk.len()..].copy_from_slice(chunk);
+                u64::from_be_bytes(limb)
+            })
+            .collect();
+        let (n, overflow) = Self::overflowing_from_limbs_slice(&limbs);
+        if overflow {
+            Err(ToUintError::ValueTooLarge(BITS, n))
+        } else {
+            Ok(n)
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for UintDecimal {
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for UintDecimal {
+    fn from(value: &Uint<BITS, LIMBS>) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<UintDecimal> for Uint<BITS, LIMBS> {
+    type Error = ParseError;
+
+    fn try_from(value: UintDecimal) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&UintDecimal> for Uint<BITS, LIMBS> {
+    type Error = ParseError;
+
+    fn try_from(value: &UintDecimal) -> Result<Self, Self::Error> {
+        // The empty string is the protobuf default, which stands for zero.
+        if value.value.is_empty() {
+            return Ok(Self::ZERO);
+        }
+        Self::from_str_radix(&value.value, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases::{U256, U64},
+        const_for, nlimbs,
+    };
+    use hex_literal::hex;
+    use proptest::proptest;
+
+    #[test]
+    fn test_bytes() {
+        let message = UintBytes::from(U256::from(0x1234));
+        assert_eq!(message.value, hex!("1234"));
+        assert_eq!(message.encode_to_vec(), hex!("0a021234"));
+        assert!(UintBytes::from(U256::ZERO).encode_to_vec().is_empty());
+        assert_eq!(
+            U64::try_from(UintBytes {
+                value: hex!("00000000000000000000ffffffffffffffff").to_vec(),
+            }),
+            Ok(U64::MAX)
+        );
+        assert_eq!(
+            U64::try_from(UintBytes {
+                value: hex!("010000000000000000").to_vec(),
+            }),
+            Err(ToUintError::ValueTooLarge(64, U64::ZERO))
+        );
+        assert_eq!(
+            Uint::<4, 1>::try_from(UintBytes {
+                value: hex!("12").to_vec(),
+            }),
+            Err(ToUintError::ValueTooLarge(4, Uint::<4, 1>::from(2)))
+        );
+    }
+
+    #[test]
+    fn test_decimal() {
+        let message = UintDecimal::from(U256::from(1234));
+        assert_eq!(message.value, "1234");
+        assert_eq!(message.encode_to_vec(), b"\x0a\x041234");
+        assert_eq!(U256::try_from(UintDecimal::default()), Ok(U256::ZERO));
+        assert!(U64::try_from(UintDecimal {
+            value: "0x12".to_string(),
+        })
+        .is_err());
+        assert!(U64::try_from(UintDecimal {
+            value: "18446744073709551616".to_string(),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let encoded = UintBytes::from(value).encode_to_vec();
+                let decoded = UintBytes::decode(&encoded[..]).unwrap();
+                assert_eq!(U::try_from(decoded), Ok(value));
+
+                let encoded = UintDecimal::from(value).encode_to_vec();
+                let decoded = UintDecimal::decode(&encoded[..]).unwrap();
+                assert_eq!(U::try_from(decoded), Ok(value));
+            });
+        });
+    }
+}
@@ -0,0 +1,189 @@
+//! Support for the [`redis`](https://crates.io/crates/redis) crate.
+//!
+//! A [`Uint`] is written as a decimal string, so small values work with
+//! numeric commands like `INCRBY`. Reading accepts integer replies and bulk
+//! or status strings in decimal or `0x`-prefixed hex.
+//!
+//! The [`AsBytes`] wrapper instead writes and reads the compact big-endian
+//! binary encoding with leading zeros removed.
+//!
+//! ```
+//! # use ruint::{aliases::U256, redis::AsBytes};
+//! # use redis::{FromRedisValue, ToRedisArgs, Value};
+//! let value = U256::from(0x1234);
+//! assert_eq!(value.to_redis_args(), vec![b"4660".to_vec()]);
+//! assert_eq!(AsBytes(value).to_redis_args(), vec![vec![0x12, 0x34]]);
+//!
+//! let read = AsBytes::<U256>::from_redis_value(&Value::Data(vec![0x12, 0x34]))?;
+//! assert_eq!(read.0, value);
+//! # Ok::<(), redis::RedisError>(())
+//! ```
+#![cfg(feature = "redis")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "redis")))]
+
+use crate::Uint;
+use redis::{
+    ErrorKind, FromRedisValue, NumericBehavior, RedisError, RedisResult, RedisWrite, ToRedisArgs,
+    Value,
+};
+use std::str::from_utf8;
+
+/// Stores the wrapped value as compact big-endian bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsBytes<T>(pub T);
+
+impl<T> From<T> for AsBytes<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<AsBytes<Self>> for Uint<BITS, LIMBS> {
+    fn from(value: AsBytes<Self>) -> Self {
+        value.0
+    }
+}
+
+/// Error in the same format as the `redis` crate's own conversions.
+fn type_error(value: &Value, detail: impl std::fmt::Display) -> RedisError {
+    RedisError::from((
+        ErrorKind::TypeError,
+        "Response was of incompatible type",
+        format!("{detail} (response was {value:?})"),
+    ))
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Parses decimal or `0x`-prefixed hex text.
+    fn from_redis_text(value: &Value, text: &str) -> RedisResult<Self> {
+        text.strip_prefix("0x")
+            .map_or_else(
+                || Self::from_str_radix(text, 10),
+                |hex| Self::from_str_radix(hex, 16),
+            )
+            .map_err(|error| type_error(value, error))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToRedisArgs for Uint<BITS, LIMBS> {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg_fmt(self);
+    }
+
+    fn describe_numeric_behavior(&self) -> NumericBehavior {
+        NumericBehavior::NumberIsInteger
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromRedisValue for Uint<BITS, LIMBS> {
+    fn from_redis_value(value: &Value) -> RedisResult<Self> {
+        match value {
+            Value::Int(int) => {
+                let int = u64::try_from(*int)
+                    .map_err(|_| type_error(value, "Negative values can not be represented"))?;
+                Self::try_from(int)
+                    .map_err(|_| type_error(value, "Value too large for target type"))
+            }
+            Value::Data(bytes) => Self::from_redis_text(value, from_utf8(bytes)?),
+            Value::Status(text) => Self::from_redis_text(value, text),
+            _ => Err(type_error(
+                value,
+                "Response type not convertible to numeric",
+            )),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToRedisArgs for AsBytes<Uint<BITS, LIMBS>> {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(&self.0.to_be_bytes_trimmed_vec());
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromRedisValue for AsBytes<Uint<BITS, LIMBS>> {
+    fn from_redis_value(value: &Value) -> RedisResult<Self> {
+        match value {
+            Value::Data(bytes) => Uint::try_from_be_slice(bytes)
+                .map(Self)
+                .ok_or_else(|| type_error(value, "Value too large for target type")),
+            _ => Err(type_error(value, "Response type not convertible to bytes")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    fn single_arg<T: ToRedisArgs>(value: &T) -> Vec<u8> {
+        let mut args = value.to_redis_args();
+        assert_eq!(args.len(), 1);
+        args.pop().unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let decimal = Value::Data(single_arg(&value));
+                assert_eq!(U::from_redis_value(&decimal).unwrap(), value);
+                let bytes = Value::Data(single_arg(&AsBytes(value)));
+                assert_eq!(AsBytes::<U>::from_redis_value(&bytes).unwrap().0, value);
+                let hex = Value::Status(format!("{value:#x}"));
+                assert_eq!(U::from_redis_value(&hex).unwrap(), value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_int() {
+        const_for!(BITS in NON_ZERO if (BITS >= 64) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: i64)| {
+                let result = U::from_redis_value(&Value::Int(value));
+                if value < 0 {
+                    assert_eq!(result.unwrap_err().kind(), ErrorKind::TypeError);
+                } else {
+                    assert_eq!(result.unwrap(), U::from(value));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_errors() {
+        type U8 = Uint<8, 1>;
+        for value in [
+            Value::Int(256),
+            Value::Data(b"256".to_vec()),
+            Value::Data(b"0x100".to_vec()),
+            Value::Data(b"12ab".to_vec()),
+            Value::Data(vec![0xff, 0xfe]),
+            Value::Nil,
+            Value::Okay,
+            Value::Bulk(vec![Value::Int(1)]),
+        ] {
+            let error = U8::from_redis_value(&value).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::TypeError);
+        }
+        for value in [Value::Data(vec![1, 0]), Value::Int(1), Value::Nil] {
+            let error = AsBytes::<U8>::from_redis_value(&value).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::TypeError);
+        }
+        assert_eq!(
+            U8::from(1).describe_numeric_behavior(),
+            NumericBehavior::NumberIsInteger
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Support for the [`rkyv`](https://crates.io/crates/rkyv) crate.
+//!
+//! Both [`Uint`] and [`Bits`] archive as [`ArchivedUint`], which stores the
+//! limbs as little-endian `u64`s regardless of the platform. Archives are
+//! therefore portable between architectures and can be memory-mapped directly.
+//!
+//! ```
+//! # use ruint::{aliases::U256, uint};
+//! let balances = vec![uint!(1_U256), U256::MAX];
+//! let bytes = rkyv::to_bytes::<_, 256>(&balances).unwrap();
+//!
+//! let archived = rkyv::check_archived_root::<Vec<U256>>(&bytes).unwrap();
+//! assert_eq!(archived[1].to_uint(), U256::MAX);
+//! ```
+#![cfg(feature = "rkyv")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "rkyv")))]
+
+use crate::{from::ToUintError, Bits, Uint};
+use rkyv::{bytecheck::CheckBytes, rend::u64_le, Archive, Deserialize, Fallible, Serialize};
+
+/// The archived form of [`Uint`] and [`Bits`].
+///
+/// The limbs are stored in little-endian byte order. Validation with
+/// [`CheckBytes`] rejects values larger than [`Uint::MAX`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ArchivedUint<const BITS: usize, const LIMBS: usize>([u64_le; LIMBS]);
+
+impl<const BITS: usize, const LIMBS: usize> ArchivedUint<BITS, LIMBS> {
+    /// Converts the archived value back to a [`Uint`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is too large for the [`Uint`]. This can only
+    /// happen for archives that have not been validated.
+    #[must_use]
+    pub fn to_uint(&self) -> Uint<BITS, LIMBS> {
+        Uint::from_limbs(self.0.map(u64_le::value))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Archive for Uint<BITS, LIMBS> {
+    type Archived = ArchivedUint<BITS, LIMBS>;
+    type Resolver = ();
+
+    unsafe fn resolve(&self, _: usize, (): Self::Resolver, out: *mut Self::Archived) {
+        out.write(ArchivedUint(self.limbs.map(u64_le::new)));
+    }
+}
+
+impl<S: Fallible + ?Sized, const BITS: usize, const LIMBS: usize> Serialize<S>
+    for Uint<BITS, LIMBS>
+{
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized, const BITS: usize, const LIMBS: usize> Deserialize<Uint<BITS, LIMBS>, D>
+    for ArchivedUint<BITS, LIMBS>
+{
+    fn deserialize(&self, _: &mut D) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        Ok(self.to_uint())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Archive for Bits<BITS, LIMBS> {
+    type Archived = ArchivedUint<BITS, LIMBS>;
+    type Resolver = ();
+
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        self.as_uint().resolve(pos, resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized, const BITS: usize, const LIMBS: usize> Serialize<S>
+    for Bits<BITS, LIMBS>
+{
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized, const BITS: usize, const LIMBS: usize> Deserialize<Bits<BITS, LIMBS>, D>
+    for ArchivedUint<BITS, LIMBS>
+{
+    fn deserialize(&self, _: &mut D) -> Result<Bits<BITS, LIMBS>, D::Error> {
+        Ok(self.to_uint().into())
+    }
+}
+
+impl<C: ?Sized, const BITS: usize, const LIMBS: usize> CheckBytes<C> for ArchivedUint<BITS, LIMBS> {
+    type Error = ToUintError<Uint<BITS, LIMBS>>;
+
+    unsafe fn check_bytes<'a>(value: *const Self, _: &mut C) -> Result<&'a Self, Self::Error> {
+        // All bit patterns of the limbs are valid, only the range is checked.
+        let archived = &*value;
+        if LIMBS > 0 && archived.0[LIMBS - 1].value() > Uint::<BITS, LIMBS>::MASK {
+            let mut limbs = archived.0.map(u64_le::value);
+            limbs[LIMBS - 1] &= Uint::<BITS, LIMBS>::MASK;
+            return Err(ToUintError::ValueTooLarge(BITS, Uint::from_limbs(limbs)));
+        }
+        Ok(archived)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> PartialEq<Uint<BITS, LIMBS>>
+    for ArchivedUint<BITS, LIMBS>
+{
+    fn eq(&self, other: &Uint<BITS, LIMBS>) -> bool {
+        self.0
+            .iter()
+            .zip(other.as_limbs())
+            .all(|(a, b)| a.value() == *b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use proptest::proptest;
+    use rkyv::{archived_root, check_archived_root, to_bytes, AlignedVec, Infallible};
+
+    #[test]
+    fn test_layout() {
+        let value = U256::from(0x0102_0304_0506_0708_u64) << 64;
+        let bytes = to_bytes::<_, 32>(&value).unwrap();
+        let mut expected = [0_u8; 32];
+        expected[8..16].copy_from_slice(&[8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(bytes.as_slice(), expected);
+        assert_eq!(check_archived_root::<U256>(&bytes).unwrap(), &value);
+    }
+
+    #[test]
+    fn test_check_bytes() {
+        let mut bytes = AlignedVec::new();
+        bytes.extend_from_slice(&[0xff, 0x01, 0, 0, 0, 0, 0, 0]);
+        assert!(check_archived_root::<Uint<9, 1>>(&bytes).is_ok());
+        assert_eq!(
+            check_archived_root::<Uint<8, 1>>(&bytes)
+                .unwrap_err()
+                .to_string(),
+            "check bytes error: Value is too large for Uint<8>"
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        // `rkyv` 0.7 can not serialize a zero-sized root value.
+        const_for!(BITS in SIZES if (BITS != 0) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let bytes = to_bytes::<_, 64>(&value).unwrap();
+                assert_eq!(bytes.len(), 8 * LIMBS);
+                let archived = check_archived_root::<U>(&bytes).unwrap();
+                assert_eq!(archived, &value);
+                let deserialized: U = archived.deserialize(&mut Infallible).unwrap();
+                assert_eq!(deserialized, value);
+
+                let bytes = to_bytes::<_, 64>(&Bits::from(value)).unwrap();
+                let archived = unsafe { archived_root::<Bits<BITS, LIMBS>>(&bytes) };
+                let deserialized: Bits<BITS, LIMBS> = archived.deserialize(&mut Infallible).unwrap();
+                assert_eq!(deserialized.into_inner(), value);
+            });
+        });
+    }
+}
@@ -0,0 +1,86 @@
+//! Support for the [`rug`](https://crates.io/crates/rug) crate.
+#![cfg(feature = "rug")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "rug")))]
+
+use crate::{from::ToUintError, Uint};
+use core::cmp::Ordering;
+use rug::{integer::Order, Integer};
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<Integer> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    fn try_from(value: Integer) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&Integer> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    fn try_from(value: &Integer) -> Result<Self, Self::Error> {
+        // The digits are those of the absolute value.
+        let digits = value.to_digits::<u64>(Order::Lsf);
+        let (n, overflow) = Self::overflowing_from_limbs_slice(&digits);
+        if value.cmp0() == Ordering::Less {
+            Err(ToUintError::ValueNegative(BITS, n))
+        } else if overflow {
+            Err(ToUintError::ValueTooLarge(BITS, n))
+        } else {
+            Ok(n)
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for Integer {
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for Integer {
+    fn from(value: &Uint<BITS, LIMBS>) -> Self {
+        Self::from_digits(value.as_limbs(), Order::Lsf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let big: Integer = value.into();
+                assert_eq!(big.to_string(), value.to_string());
+                let back: U = big.try_into().unwrap();
+                assert_eq!(back, value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_errors() {
+        type U = Uint<64, 1>;
+        type Z = Uint<0, 0>;
+        let big = Integer::from(u64::MAX) + 2;
+        assert_eq!(
+            U::try_from(&big),
+            Err(ToUintError::ValueTooLarge(64, U::from(1)))
+        );
+        let negative = Integer::from(-5);
+        assert_eq!(
+            U::try_from(negative),
+            Err(ToUintError::ValueNegative(64, U::from(5)))
+        );
+        assert_eq!(Z::try_from(Integer::new()), Ok(Z::ZERO));
+        assert_eq!(
+            Z::try_from(Integer::from(1)),
+            Err(ToUintError::ValueTooLarge(0, Z::ZERO))
+        );
+    }
+}
@@ -0,0 +1,182 @@
+//! Support for the [`rusqlite`](https://crates.io/crates/rusqlite) crate.
+//!
+//! Values are stored as a big-endian `BLOB`. Reading also accepts `INTEGER`
+//! values and `TEXT` as decimal or `0x`-prefixed hex. Failed reads return a
+//! [`FromSqliteError`] in [`FromSqlError::Other`].
+//!
+//! Note: [`Uint`]s are never null, use [`Option<Uint>`] instead.
+#![cfg(feature = "rusqlite")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "rusqlite")))]
+
+use crate::{ParseError, ParseErrorKind, Uint};
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use std::str::from_utf8;
+use thiserror::Error;
+
+/// Error reading a [`Uint`] from SQLite.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Error)]
+pub enum FromSqliteError {
+    /// The `BLOB`, `INTEGER` or `TEXT` value is too large to fit the target
+    /// type.
+    #[error("Value too large for target type")]
+    Overflow,
+
+    /// The `INTEGER` value `.0` is negative.
+    #[error("Negative value {0} can not be represented")]
+    Negative(i64),
+
+    /// The `TEXT` value is not a decimal or `0x`-prefixed hex number.
+    #[error("Invalid text value: {0}")]
+    InvalidText(ParseError),
+}
+
+impl From<FromSqliteError> for FromSqlError {
+    fn from(error: FromSqliteError) -> Self {
+        Self::Other(Box::new(error))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToSql for Uint<BITS, LIMBS> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_be_bytes_vec()))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromSql for Uint<BITS, LIMBS> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Blob(bytes) => {
+                Ok(Self::try_from_be_slice(bytes).ok_or(FromSqliteError::Overflow)?)
+            }
+            ValueRef::Integer(value) => {
+                let value = u64::try_from(value).map_err(|_| FromSqliteError::Negative(value))?;
+                Ok(Self::try_from(value).map_err(|_| FromSqliteError::Overflow)?)
+            }
+            ValueRef::Text(text) => {
+                let text = from_utf8(text).map_err(|error| FromSqlError::Other(Box::new(error)))?;
+                let result = text.strip_prefix("0x").map_or_else(
+                    || Self::from_str_radix(text, 10),
+                    |hex| Self::from_str_radix(hex, 16),
+                );
+                Ok(result.map_err(|error| match error.kind() {
+                    ParseErrorKind::Overflow | ParseErrorKind::InputTooLong(_) => {
+                        FromSqliteError::Overflow
+                    }
+                    _ => FromSqliteError::InvalidText(error),
+                })?)
+            }
+            ValueRef::Null | ValueRef::Real(_) => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+    use rusqlite::{params, Connection};
+
+    fn read_error<T: ToSql, const BITS: usize, const LIMBS: usize>(
+        conn: &Connection,
+        value: T,
+    ) -> FromSqlError {
+        let error = conn
+            .query_row("SELECT ?", params![value], |row| {
+                row.get::<_, Uint<BITS, LIMBS>>(0)
+            })
+            .unwrap_err();
+        match error {
+            rusqlite::Error::FromSqlConversionFailure(_, _, error) => FromSqlError::Other(error),
+            rusqlite::Error::InvalidColumnType(..) => FromSqlError::InvalidType,
+            error => panic!("unexpected error {error}"),
+        }
+    }
+
+    fn sqlite_error<T: ToSql, const BITS: usize, const LIMBS: usize>(
+        conn: &Connection,
+        value: T,
+    ) -> FromSqliteError {
+        match read_error::<T, BITS, LIMBS>(conn, value) {
+            FromSqlError::Other(error) => *error.downcast::<FromSqliteError>().unwrap(),
+            error => panic!("unexpected error {error}"),
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE test (value BLOB)", []).unwrap();
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                conn.execute("DELETE FROM test", []).unwrap();
+                conn.execute("INSERT INTO test (value) VALUES (?)", [value]).unwrap();
+                let (kind, read): (String, Uint<BITS, LIMBS>) = conn
+                    .query_row("SELECT typeof(value), value FROM test", [], |row| {
+                        Ok((row.get(0)?, row.get(1)?))
+                    })
+                    .unwrap();
+                assert_eq!(kind, "blob");
+                assert_eq!(read, value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_integer_and_text() {
+        let conn = Connection::open_in_memory().unwrap();
+        const_for!(BITS in NON_ZERO if (BITS >= 64) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: i64)| {
+                if value < 0 {
+                    let error = sqlite_error::<_, BITS, LIMBS>(&conn, value);
+                    assert_eq!(error, FromSqliteError::Negative(value));
+                } else {
+                    let read: U = conn.query_row("SELECT ?", [value], |row| row.get(0)).unwrap();
+                    assert_eq!(read, U::from(value));
+                }
+            });
+            proptest!(|(value: U)| {
+                for text in [value.to_string(), format!("{value:#x}")] {
+                    let read: U = conn.query_row("SELECT ?", [&text], |row| row.get(0)).unwrap();
+                    assert_eq!(read, value);
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_errors() {
+        let conn = Connection::open_in_memory().unwrap();
+        assert_eq!(
+            sqlite_error::<_, 8, 1>(&conn, 256_i64),
+            FromSqliteError::Overflow
+        );
+        assert_eq!(
+            sqlite_error::<_, 8, 1>(&conn, vec![1_u8, 0]),
+            FromSqliteError::Overflow
+        );
+        assert_eq!(
+            sqlite_error::<_, 8, 1>(&conn, "256"),
+            FromSqliteError::Overflow
+        );
+        assert_eq!(
+            sqlite_error::<_, 8, 1>(&conn, "0x100"),
+            FromSqliteError::Overflow
+        );
+        assert!(matches!(
+            sqlite_error::<_, 64, 1>(&conn, "12ab"),
+            FromSqliteError::InvalidText(_)
+        ));
+        assert_eq!(
+            read_error::<_, 64, 1>(&conn, 1.5_f64),
+            FromSqlError::InvalidType
+        );
+        assert_eq!(
+            read_error::<_, 64, 1>(&conn, None::<i64>),
+            FromSqlError::InvalidType
+        );
+    }
+}
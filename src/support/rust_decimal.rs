@@ -0,0 +1,121 @@
+//! Support for the [`rust_decimal`](https://crates.io/crates/rust_decimal) crate.
+#![cfg(feature = "rust_decimal")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "rust_decimal")))]
+
+use super::decimal::{private::Sealed, DecimalError, DecimalValue};
+use rust_decimal::Decimal;
+
+impl Sealed for Decimal {}
+
+impl DecimalValue for Decimal {
+    fn to_decimal_parts(&self) -> (bool, Vec<u8>, i64) {
+        let mut mantissa = self.mantissa().unsigned_abs();
+        let mut digits = Vec::new();
+        while mantissa != 0 {
+            #[allow(clippy::cast_possible_truncation)] // Digits are less than 10
+            digits.push((mantissa % 10) as u8);
+            mantissa /= 10;
+        }
+        digits.reverse();
+        (self.is_sign_negative(), digits, self.scale().into())
+    }
+
+    fn from_decimal_parts(mut digits: Vec<u8>, mut scale: u32) -> Result<Self, DecimalError> {
+        // Trailing zeros can be dropped to fit the scale.
+        let requested = scale;
+        while scale > Self::MAX_SCALE && !matches!(digits.last(), Some(1..=9)) {
+            digits.pop();
+            scale -= 1;
+        }
+        if scale > Self::MAX_SCALE {
+            return Err(DecimalError::ScaleTooLarge(requested));
+        }
+        let mantissa = digits
+            .into_iter()
+            .try_fold(0_i128, |mantissa, digit| {
+                mantissa.checked_mul(10)?.checked_add(digit.into())
+            })
+            .ok_or(DecimalError::Overflow)?;
+        Self::try_from_i128_with_scale(mantissa, scale).map_err(|_| DecimalError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{aliases::U256, const_for, nlimbs, DecimalError, Rounding, Uint};
+    use proptest::proptest;
+    use rust_decimal::Decimal;
+
+    fn decimal(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_from_decimal() {
+        assert_eq!(
+            U256::from_decimal(&decimal("1.5"), 18),
+            Ok(U256::from(1_500_000_000_000_000_000_u64))
+        );
+        assert_eq!(
+            U256::from_decimal(&decimal("0.000001"), 6),
+            Ok(U256::from(1))
+        );
+        assert_eq!(
+            U256::from_decimal(&decimal("0.0000001"), 6),
+            Err(DecimalError::Inexact)
+        );
+        assert_eq!(
+            U256::from_decimal_rounded(&decimal("0.0000005"), 6, Rounding::HalfUp),
+            Ok(U256::from(1))
+        );
+        assert_eq!(
+            U256::from_decimal(&decimal("-1"), 6),
+            Err(DecimalError::Negative)
+        );
+        assert_eq!(U256::from_decimal(&decimal("-0"), 6), Ok(U256::ZERO));
+        assert_eq!(
+            U256::from_decimal(&Decimal::MAX, 48),
+            Ok(U256::from(u128::MAX >> 32) * U256::from(10).pow(48))
+        );
+        assert_eq!(
+            U256::from_decimal(&Decimal::MAX, 49),
+            Err(DecimalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_to_decimal() {
+        assert_eq!(
+            U256::from(1_500_000_000_000_000_000_u64).to_decimal(18),
+            Ok(decimal("1.5"))
+        );
+        assert_eq!(U256::ZERO.to_decimal(100), Ok(Decimal::ZERO));
+        assert_eq!(
+            U256::from(10).pow(40).to_decimal::<Decimal>(40),
+            Ok(Decimal::ONE)
+        );
+        assert_eq!(
+            U256::from(11).to_decimal::<Decimal>(29),
+            Err(DecimalError::ScaleTooLarge(29))
+        );
+        assert_eq!(U256::from(u128::MAX >> 32).to_decimal(0), Ok(Decimal::MAX));
+        assert_eq!(
+            U256::from(u128::MAX >> 31).to_decimal::<Decimal>(0),
+            Err(DecimalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: u128, scale in 0_u32..=28)| {
+                let value = U::saturating_from(value >> 32);
+                let decimal: Decimal = value.to_decimal(scale).unwrap();
+                assert_eq!(U::from_decimal(&decimal, scale), Ok(value));
+                assert_eq!(decimal.scale(), scale);
+            });
+        });
+    }
+}
@@ -2,7 +2,7 @@
 #![cfg(feature = "serde")]
 #![cfg_attr(has_doc_cfg, doc(cfg(feature = "serde")))]
 
-use crate::{nbytes, Uint};
+use crate::{nbytes, string::max_digits, ParseError, ParseErrorKind, Uint};
 use core::fmt::{Formatter, Result as FmtResult};
 use serde::{
     de::{Error, Unexpected, Visitor},
@@ -60,6 +60,15 @@ impl<'de, const BITS: usize, const LIMBS: usize> Visitor<'de> for StrVisitor<BIT
         E: Error,
     {
         let value = trim_hex_prefix(value);
+        // Reject long untrusted inputs before converting them.
+        let max = max_digits(BITS, 16);
+        if value.trim_start_matches('0').len() > max {
+            return Err(Error::custom(ParseError::new(
+                ParseErrorKind::InputTooLong(max),
+                0,
+                16,
+            )));
+        }
         let mut limbs = [0; LIMBS];
         for (i, chunk) in value.as_bytes().rchunks(16).enumerate() {
             let chunk = str::from_utf8(chunk)
@@ -119,9 +128,20 @@ fn trim_hex_prefix(str: &str) -> &str {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{const_for, nlimbs};
+    use crate::{aliases::U64, const_for, nlimbs};
     use proptest::proptest;
 
+    #[test]
+    fn test_input_too_long() {
+        let json = format!("\"0x{}1\"", "0".repeat(1_000_000));
+        assert_eq!(serde_json::from_str::<U64>(&json).unwrap(), U64::from(1));
+        let json = format!("\"0x1{}\"", "0".repeat(16));
+        assert_eq!(
+            serde_json::from_str::<U64>(&json).unwrap_err().to_string(),
+            "value at byte 0 is too long, at most 16 digits are allowed at line 1 column 21"
+        );
+    }
+
     #[test]
     fn test_serde_human_readable() {
         const_for!(BITS in SIZES {
@@ -1,15 +1,31 @@
 //! Support for the [`sqlx`](https://crates.io/crates/sqlx) crate.
 //!
-//! Currently only encodes to/from a big-endian byte array.
+//! By default a [`Uint`] is stored as a big-endian byte array (`BYTEA` in
+//! Postgres, `BLOB` in MySQL and SQLite). Other column types are selected by
+//! wrapping the value:
+//!
+//! * [`AsNumeric`] stores it as a Postgres `NUMERIC` or MySQL `DECIMAL`.
+//! * [`AsText`] stores it as a decimal string in a `TEXT` column.
+//!
+//! ```
+//! # use ruint::{aliases::U256, sqlx::AsText};
+//! let value = AsText(U256::from(42));
+//! let value: U256 = value.into();
+//! assert_eq!(value, U256::from(42));
+//! ```
 #![cfg(feature = "sqlx")]
 #![cfg_attr(has_doc_cfg, doc(cfg(feature = "sqlx")))]
 
+use super::pg_numeric::{from_numeric, to_numeric};
 use crate::Uint;
 use sqlx_core::{
     database::{Database, HasArguments, HasValueRef},
     decode::Decode,
     encode::{Encode, IsNull},
     error::BoxDynError,
+    mysql::{MySql, MySqlTypeInfo, MySqlValueRef},
+    postgres::{types::Oid, PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres},
+    type_info::TypeInfo,
     types::Type,
 };
 use thiserror::Error;
@@ -18,6 +34,55 @@ use thiserror::Error;
 enum DecodeError {
     #[error("Value too large for target type")]
     Overflow,
+
+    #[error("Value is not an integer")]
+    NotAnInteger,
+}
+
+/// Stores the wrapped value as a Postgres `NUMERIC` or MySQL `DECIMAL`.
+///
+/// # Panics
+///
+/// Encoding for Postgres panics if the value has more than `i16::MAX` base
+/// 10000 digits, which `NUMERIC` can not hold. This only happens for `BITS`
+/// above 435398.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsNumeric<T>(pub T);
+
+/// Stores the wrapped value as a decimal string in a `TEXT` column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsText<T>(pub T);
+
+macro_rules! impl_wrapper_from {
+    ($($wrapper:ident),*) => {$(
+        impl<T> From<T> for $wrapper<T> {
+            fn from(value: T) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> From<$wrapper<Uint<BITS, LIMBS>>>
+            for Uint<BITS, LIMBS>
+        {
+            fn from(value: $wrapper<Self>) -> Self {
+                value.0
+            }
+        }
+    )*};
+}
+
+impl_wrapper_from!(AsNumeric, AsText);
+
+/// Parses a decimal number, allowing a fraction of zeros like
+/// `NUMERIC(p, s)` and `DECIMAL(p, s)` columns return.
+fn from_decimal_str<const BITS: usize, const LIMBS: usize>(
+    text: &str,
+) -> Result<Uint<BITS, LIMBS>, BoxDynError> {
+    let (integer, fraction) = text.split_once('.').unwrap_or((text, ""));
+    if !fraction.bytes().all(|byte| byte == b'0') {
+        return Err(DecodeError::NotAnInteger.into());
+    }
+    Ok(Uint::from_str_radix(integer, 10)?)
 }
 
 impl<const BITS: usize, const LIMBS: usize, DB: Database> Type<DB> for Uint<BITS, LIMBS>
@@ -51,3 +116,235 @@ where
         Self::try_from_be_slice(bytes.as_slice()).ok_or_else(|| DecodeError::Overflow.into())
     }
 }
+
+impl<const BITS: usize, const LIMBS: usize, DB: Database> Type<DB> for AsText<Uint<BITS, LIMBS>>
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'a, const BITS: usize, const LIMBS: usize, DB: Database> Encode<'a, DB>
+    for AsText<Uint<BITS, LIMBS>>
+where
+    String: Encode<'a, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'a>>::ArgumentBuffer) -> IsNull {
+        self.0.to_string().encode_by_ref(buf)
+    }
+}
+
+impl<'a, const BITS: usize, const LIMBS: usize, DB: Database> Decode<'a, DB>
+    for AsText<Uint<BITS, LIMBS>>
+where
+    &'a str: Decode<'a, DB>,
+{
+    fn decode(value: <DB as HasValueRef<'a>>::ValueRef) -> Result<Self, BoxDynError> {
+        let text = <&str as Decode<DB>>::decode(value)?;
+        from_decimal_str(text).map(Self)
+    }
+}
+
+/// Object identifier of the built-in `NUMERIC` type.
+const NUMERIC_OID: Oid = Oid(1700);
+
+impl<const BITS: usize, const LIMBS: usize> Type<Postgres> for AsNumeric<Uint<BITS, LIMBS>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_oid(NUMERIC_OID)
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        ty.name() == "NUMERIC"
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Encode<'_, Postgres> for AsNumeric<Uint<BITS, LIMBS>> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        // Like sqlx's own `BigDecimal`, values that do not fit a `NUMERIC` panic.
+        let numeric = to_numeric(&self.0).expect("Value too large for Postgres NUMERIC type");
+        buf.extend_from_slice(&numeric);
+        IsNull::No
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Decode<'_, Postgres> for AsNumeric<Uint<BITS, LIMBS>> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => Ok(Self(from_numeric(value.as_bytes()?)?)),
+            PgValueFormat::Text => from_decimal_str(value.as_str()?).map(Self),
+        }
+    }
+}
+
+// MySQL sends `DECIMAL` values as strings in both the text and binary
+// protocol, and converts string parameters on insert.
+impl<const BITS: usize, const LIMBS: usize> Type<MySql> for AsNumeric<Uint<BITS, LIMBS>> {
+    fn type_info() -> MySqlTypeInfo {
+        <str as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        ty.name() == "DECIMAL" || <str as Type<MySql>>::compatible(ty)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Encode<'_, MySql> for AsNumeric<Uint<BITS, LIMBS>> {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        <String as Encode<MySql>>::encode_by_ref(&self.0.to_string(), buf)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Decode<'_, MySql> for AsNumeric<Uint<BITS, LIMBS>> {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let text = <&str as Decode<MySql>>::decode(value)?;
+        from_decimal_str(text).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases::U256, const_for, nlimbs, support::pg_numeric::NumericError, BaseConvertError,
+        ParseError, ParseErrorKind,
+    };
+    use hex_literal::hex;
+    use proptest::proptest;
+    use sqlx_core::{
+        connection::Connection, query::query, query_scalar::query_scalar, sqlite::SqliteConnection,
+        test_block_on,
+    };
+
+    #[test]
+    fn test_sqlite_roundtrip() {
+        test_block_on(async {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            query("CREATE TABLE test (text TEXT, blob BLOB)")
+                .execute(&mut conn)
+                .await
+                .unwrap();
+            const_for!(BITS in SIZES {
+                const LIMBS: usize = nlimbs(BITS);
+                type U = Uint<BITS, LIMBS>;
+                for value in [U::ZERO, U::MAX, U::MAX >> (BITS / 2)] {
+                    query("DELETE FROM test").execute(&mut conn).await.unwrap();
+                    query("INSERT INTO test (text, blob) VALUES (?, ?)")
+                        .bind(AsText(value))
+                        .bind(value)
+                        .execute(&mut conn)
+                        .await
+                        .unwrap();
+                    let text: AsText<U> = query_scalar("SELECT text FROM test")
+                        .fetch_one(&mut conn)
+                        .await
+                        .unwrap();
+                    let blob: U = query_scalar("SELECT blob FROM test")
+                        .fetch_one(&mut conn)
+                        .await
+                        .unwrap();
+                    assert_eq!(text.0, value);
+                    assert_eq!(blob, value);
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_sqlite_text_fraction() {
+        test_block_on(async {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            let value: AsText<Uint<64, 1>> = query_scalar("SELECT '1234.000'")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap();
+            assert_eq!(value.0, Uint::from(1234));
+            let result: Result<AsText<Uint<64, 1>>, _> =
+                query_scalar("SELECT '1234.5'").fetch_one(&mut conn).await;
+            assert!(result.is_err());
+            let result: Result<AsText<Uint<8, 1>>, _> =
+                query_scalar("SELECT '256'").fetch_one(&mut conn).await;
+            assert!(result.is_err());
+        });
+    }
+
+    fn encode_pg<const BITS: usize, const LIMBS: usize>(value: Uint<BITS, LIMBS>) -> Vec<u8> {
+        let mut buf = PgArgumentBuffer::default();
+        let _ = Encode::<Postgres>::encode_by_ref(&AsNumeric(value), &mut buf);
+        buf.to_vec()
+    }
+
+    #[test]
+    fn test_pg_numeric_encode() {
+        // Digit count, exponent of the first digit, sign and scale, followed by
+        // the base 10000 digits without trailing zeros.
+        assert_eq!(encode_pg(U256::ZERO), hex!("0000 0000 0000 0000"));
+        assert_eq!(
+            encode_pg(U256::from(10000)),
+            hex!("0001 0001 0000 0000 0001")
+        );
+        assert_eq!(
+            encode_pg(U256::from(123_456_789)),
+            hex!("0003 0002 0000 0000 0001 0929 1a85")
+        );
+        assert_eq!(
+            encode_pg(U256::MAX),
+            hex!(
+                "0014 0013 0000 0000"
+                "000b 16a0 037c 0e93 1833 108b 1bba 1390 1adf 0311"
+                "0cc5 2676 19a4 0234 018a 167e 0fa7 23ab 0b93 26cf"
+            )
+        );
+    }
+
+    #[test]
+    fn test_pg_numeric_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                assert_eq!(from_numeric::<BITS, LIMBS>(&encode_pg(value)), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_pg_numeric_decode() {
+        type U = Uint<64, 1>;
+        let numeric = hex!("0001 0001 0000 0000 0001");
+        assert_eq!(from_numeric::<64, 1>(&numeric), Ok(U::from(10000)));
+        let negative = hex!("0001 0000 4000 0000 0001");
+        assert_eq!(from_numeric::<64, 1>(&negative), Err(NumericError::Invalid));
+        let digit = hex!("0001 0000 0000 0000 2710");
+        assert_eq!(from_numeric::<64, 1>(&digit), Err(NumericError::Invalid));
+        let overflow = hex!("0001 0001 0000 0000 0100");
+        assert_eq!(
+            from_numeric::<8, 1>(&overflow),
+            Err(NumericError::BaseConvert(BaseConvertError::Overflow))
+        );
+    }
+
+    #[test]
+    fn test_decimal_str_decode() {
+        type U = Uint<64, 1>;
+        assert_eq!(from_decimal_str::<64, 1>("12").unwrap(), U::from(12));
+        assert_eq!(from_decimal_str::<64, 1>("12.000").unwrap(), U::from(12));
+        assert_eq!(from_decimal_str::<64, 1>("12.").unwrap(), U::from(12));
+        let error = from_decimal_str::<64, 1>("12.5").unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<DecodeError>(),
+            Some(DecodeError::NotAnInteger)
+        ));
+        let error = from_decimal_str::<8, 1>("256.0").unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<ParseError>().map(ParseError::kind),
+            Some(ParseErrorKind::Overflow)
+        );
+        assert!(from_decimal_str::<64, 1>("-1").is_err());
+        assert!(from_decimal_str::<64, 1>("1e3").is_err());
+    }
+}
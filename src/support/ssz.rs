@@ -0,0 +1,178 @@
+//! Support for the [`ethereum_ssz`](https://crates.io/crates/ethereum_ssz) and
+//! [`tree_hash`](https://crates.io/crates/tree_hash) crates.
+//!
+//! Values are encoded as fixed-size little-endian byte strings of
+//! [`Uint::BYTES`] length, so `Uint<256, 4>` is the SSZ `uint256`.
+//!
+//! For Merkleization, sizes of up to 256 bits that take a power of two number
+//! of bytes are basic types and pack multiple values into a single chunk. Other
+//! sizes are hashed like a fixed-length byte vector.
+//!
+//! See <https://github.com/ethereum/consensus-specs/blob/dev/ssz/simple-serialize.md>
+#![cfg(feature = "ssz")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "ssz")))]
+
+use crate::Uint;
+use ssz::{Decode, DecodeError, Encode};
+use tree_hash::{merkle_root, Hash256, PackedEncoding, TreeHash, TreeHashType, HASHSIZE};
+
+impl<const BITS: usize, const LIMBS: usize> Encode for Uint<BITS, LIMBS> {
+    fn is_ssz_fixed_len() -> bool {
+        true
+    }
+
+    fn ssz_fixed_len() -> usize {
+        Self::BYTES
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        Self::BYTES
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        #[cfg(target_endian = "little")]
+        buf.extend_from_slice(self.as_le_slice());
+
+        // On big endian we use an intermediate
+        #[cfg(not(target_endian = "little"))]
+        buf.extend_from_slice(&self.to_le_bytes_vec());
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Decode for Uint<BITS, LIMBS> {
+    fn is_ssz_fixed_len() -> bool {
+        true
+    }
+
+    fn ssz_fixed_len() -> usize {
+        Self::BYTES
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() != Self::BYTES {
+            return Err(DecodeError::InvalidByteLength {
+                len:      bytes.len(),
+                expected: Self::BYTES,
+            });
+        }
+        Self::try_from_le_slice(bytes)
+            .ok_or_else(|| DecodeError::BytesInvalid(format!("Value too large for Uint<{BITS}>")))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Whether this size is an SSZ basic type that is packed in chunks.
+    const fn is_ssz_basic() -> bool {
+        Self::BYTES.is_power_of_two() && Self::BYTES <= HASHSIZE
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TreeHash for Uint<BITS, LIMBS> {
+    fn tree_hash_type() -> TreeHashType {
+        if Self::is_ssz_basic() {
+            TreeHashType::Basic
+        } else {
+            TreeHashType::Vector
+        }
+    }
+
+    fn tree_hash_packed_encoding(&self) -> PackedEncoding {
+        assert!(Self::is_ssz_basic(), "Vector should never be packed.");
+
+        #[cfg(target_endian = "little")]
+        return PackedEncoding::from_slice(self.as_le_slice());
+
+        #[cfg(not(target_endian = "little"))]
+        PackedEncoding::from_slice(&self.to_le_bytes_vec())
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        assert!(Self::is_ssz_basic(), "Vector should never be packed.");
+        HASHSIZE / Self::BYTES
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        // For values fitting a single chunk this is the zero padded value.
+        #[cfg(target_endian = "little")]
+        return merkle_root(self.as_le_slice(), 0);
+
+        #[cfg(not(target_endian = "little"))]
+        merkle_root(&self.to_le_bytes_vec(), 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases::{U128, U256, U512, U64, U8},
+        const_for, nlimbs,
+    };
+    use hex_literal::hex;
+    use proptest::proptest;
+
+    #[test]
+    fn test_ssz() {
+        assert_eq!(U8::from(0x12).as_ssz_bytes(), hex!("12"));
+        assert_eq!(
+            U256::from(0x1234_5678).as_ssz_bytes(),
+            hex!("7856341200000000000000000000000000000000000000000000000000000000")
+        );
+        assert_eq!(
+            U256::from_ssz_bytes(&hex!("12")),
+            Err(DecodeError::InvalidByteLength {
+                len:      1,
+                expected: 32,
+            })
+        );
+        assert!(Uint::<12, 1>::from_ssz_bytes(&hex!("ffff")).is_err());
+    }
+
+    #[test]
+    fn test_ssz_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let serialized = value.as_ssz_bytes();
+                assert_eq!(serialized.len(), value.ssz_bytes_len());
+                assert_eq!(serialized, value.to_le_bytes_vec());
+                let deserialized = Uint::from_ssz_bytes(&serialized).unwrap();
+                assert_eq!(value, deserialized);
+            });
+        });
+    }
+
+    #[test]
+    fn test_ssz_native_compat() {
+        proptest!(|(value: u64)| {
+            assert_eq!(U64::from(value).as_ssz_bytes(), value.as_ssz_bytes());
+            assert_eq!(U64::from(value).tree_hash_root(), value.tree_hash_root());
+            assert_eq!(
+                U64::from(value).tree_hash_packed_encoding(),
+                value.tree_hash_packed_encoding()
+            );
+            assert_eq!(U64::tree_hash_packing_factor(), u64::tree_hash_packing_factor());
+        });
+        proptest!(|(value: u128)| {
+            assert_eq!(U128::from(value).as_ssz_bytes(), value.as_ssz_bytes());
+        });
+    }
+
+    #[test]
+    fn test_tree_hash() {
+        assert_eq!(U256::tree_hash_type(), TreeHashType::Basic);
+        assert_eq!(U256::tree_hash_packing_factor(), 1);
+        assert_eq!(
+            U256::from(0x1234_5678).tree_hash_root(),
+            Hash256::from(hex!(
+                "7856341200000000000000000000000000000000000000000000000000000000"
+            ))
+        );
+
+        // Larger sizes are hashed as byte vectors.
+        assert_eq!(U512::tree_hash_type(), TreeHashType::Vector);
+        let value = U512::from(1) << 256;
+        let bytes = value.to_le_bytes_vec();
+        assert_eq!(value.tree_hash_root(), merkle_root(&bytes, 2));
+    }
+}
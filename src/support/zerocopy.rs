@@ -0,0 +1,69 @@
+//! Support for the [`zerocopy`](https://crates.io/crates/zerocopy) crate.
+//!
+//! [`Uint`] is `#[repr(transparent)]` over `[u64; LIMBS]`, so every [`Uint`]
+//! is [`FromZeroes`]. [`AsBytes`] and [`FromBytes`] are implemented when
+//! `BITS == 64 * LIMBS`, for `LIMBS` from 0 to 16, 32 and 64.
+//!
+//! ```
+//! # use ruint::aliases::U256;
+//! use zerocopy::{AsBytes, FromBytes};
+//!
+//! let values = [U256::from(1), U256::MAX];
+//! let bytes = values.as_bytes();
+//! assert_eq!(bytes.len(), 64);
+//! assert_eq!(U256::slice_from(bytes).unwrap(), values);
+//! ```
+#![cfg(feature = "zerocopy")]
+#![cfg_attr(has_doc_cfg, doc(cfg(feature = "zerocopy")))]
+
+use crate::Uint;
+use zerocopy::{AsBytes, FromBytes, FromZeroes};
+
+// Safety: the only field is `[u64; LIMBS]` for which zero is valid.
+unsafe impl<const BITS: usize, const LIMBS: usize> FromZeroes for Uint<BITS, LIMBS> {
+    fn only_derive_is_allowed_to_implement_this_trait() {}
+}
+
+macro_rules! impl_bytes {
+    ($($limbs:literal)*) => {
+        $(
+            // Safety: `#[repr(transparent)]` over `[u64; LIMBS]` which has no
+            // padding, and without unused high bits every bit pattern is valid.
+            unsafe impl AsBytes for Uint<{ 64 * $limbs }, $limbs> {
+                fn only_derive_is_allowed_to_implement_this_trait() {}
+            }
+
+            unsafe impl FromBytes for Uint<{ 64 * $limbs }, $limbs> {
+                fn only_derive_is_allowed_to_implement_this_trait() {}
+            }
+        )*
+    };
+}
+
+impl_bytes!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 32 64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::{U128, U256};
+    use proptest::proptest;
+
+    #[test]
+    fn test_zeroes() {
+        assert_eq!(U256::new_zeroed(), U256::ZERO);
+        assert_eq!(Uint::<160, 3>::new_zeroed(), Uint::ZERO);
+    }
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        proptest!(|(values: Vec<U128>)| {
+            let bytes = values.as_bytes();
+            assert_eq!(bytes.len(), 16 * values.len());
+            assert_eq!(U128::slice_from(bytes).unwrap(), values);
+            for (value, chunk) in values.iter().zip(bytes.chunks_exact(16)) {
+                assert_eq!(value.as_bytes(), chunk);
+                assert_eq!(U128::read_from(chunk), Some(*value));
+            }
+        });
+    }
+}